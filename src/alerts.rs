@@ -0,0 +1,651 @@
+use owo_colors::OwoColorize;
+
+use crate::analyze;
+use crate::state::{
+    self, Ack, AlertLevel, AlertState, AlertTransition, Analysis, Config, SignalVector, Trend, Zone,
+};
+
+/// Transitions kept in alert-state.json.
+const MAX_TRANSITIONS: usize = 100;
+
+/// Feed the latest analysis into the persisted alert machine and make the
+/// analysis report the machine's level rather than the raw observation.
+pub fn apply(
+    analysis: &mut Analysis,
+    history: &[SignalVector],
+    config: &Config,
+) -> Result<(), String> {
+    let Some(latest) = history.last() else {
+        return Ok(());
+    };
+    let mut alert_state = state::load_alert_state()?;
+    step(&mut alert_state, analysis, &latest.timestamp, config);
+    state::save_alert_state(&alert_state)?;
+    analysis.alert_level = alert_state.level;
+    Ok(())
+}
+
+/// Advance the machine by one collection. Re-running on the same collection
+/// (e.g. a manual `analyze`) leaves the state untouched.
+pub fn step(alert_state: &mut AlertState, analysis: &Analysis, timestamp: &str, config: &Config) {
+    if alert_state.last_evaluated.as_deref() == Some(timestamp) {
+        return;
+    }
+    advance(alert_state, analysis, timestamp, config);
+}
+
+/// Alert level the machine would have held after each collection in history.
+pub fn replay(history: &[SignalVector], resets: &[String], config: &Config) -> Vec<AlertLevel> {
+    let mut alert_state = AlertState::default();
+    (1..=history.len())
+        .map(|end| {
+            let analysis = analyze::run(&history[..end], resets, config);
+            advance(
+                &mut alert_state,
+                &analysis,
+                &history[end - 1].timestamp,
+                config,
+            );
+            alert_state.level
+        })
+        .collect()
+}
+
+fn advance(alert_state: &mut AlertState, analysis: &Analysis, timestamp: &str, config: &Config) {
+    alert_state.last_evaluated = Some(timestamp.to_string());
+    advance_level(alert_state, analysis, timestamp, config);
+    prune_acks(alert_state, analysis, timestamp);
+}
+
+fn advance_level(
+    alert_state: &mut AlertState,
+    analysis: &Analysis,
+    timestamp: &str,
+    config: &Config,
+) {
+    // Per-signal worsening streaks
+    for &name in analyze::SIGNAL_NAMES {
+        let worsening = analysis
+            .signals
+            .get(name)
+            .is_some_and(|t| t.trend == Trend::Worsening);
+        if worsening {
+            *alert_state.streaks.entry(name.to_string()).or_insert(0) += 1;
+        } else {
+            alert_state.streaks.remove(name);
+        }
+    }
+
+    let mut contributing: Vec<String> = analysis
+        .signals
+        .iter()
+        .filter(|(_, t)| t.trend == Trend::Worsening || t.zone == Some(Zone::Concern))
+        .map(|(name, _)| name.clone())
+        .collect();
+    contributing.sort();
+
+    let mut sustained: Vec<String> = alert_state
+        .streaks
+        .iter()
+        .filter(|(_, &n)| n >= config.alert_after_sessions)
+        .map(|(name, _)| name.clone())
+        .collect();
+    sustained.sort();
+
+    // Sustained decline of any single signal, or CONCERN held long enough, escalates to ALERT
+    let concern_held = analysis.alert_level == AlertLevel::Concern
+        && (alert_state.level == AlertLevel::Alert
+            || (alert_state.level == AlertLevel::Concern
+                && alert_state.sessions_at_level + 1 >= config.alert_after_sessions));
+    let (observed, reason) = if !sustained.is_empty() {
+        (
+            AlertLevel::Alert,
+            format!(
+                "{} worsening for {}+ sessions",
+                sustained.join(", "),
+                config.alert_after_sessions
+            ),
+        )
+    } else if concern_held {
+        (
+            AlertLevel::Alert,
+            format!(
+                "CONCERN sustained for {}+ sessions",
+                config.alert_after_sessions
+            ),
+        )
+    } else {
+        (analysis.alert_level, String::new())
+    };
+
+    if observed == alert_state.level {
+        alert_state.pending = None;
+        alert_state.pending_count = 0;
+        alert_state.sessions_at_level += 1;
+        alert_state.signals = contributing;
+        return;
+    }
+
+    // Hysteresis: the new level must hold for several sessions in a row
+    let escalating = observed > alert_state.level;
+    let target = match alert_state.pending {
+        Some(p) if (p > alert_state.level) == escalating => {
+            alert_state.pending_count += 1;
+            if observed == AlertLevel::Alert {
+                observed
+            } else if escalating {
+                p.min(observed)
+            } else {
+                p.max(observed)
+            }
+        }
+        _ => {
+            alert_state.pending_count = 1;
+            observed
+        }
+    };
+    alert_state.pending = Some(target);
+
+    let needed = if observed == AlertLevel::Alert {
+        1
+    } else if escalating {
+        config.alert_escalate_after
+    } else {
+        config.alert_clear_after
+    };
+    if alert_state.pending_count < needed.max(1) {
+        alert_state.sessions_at_level += 1;
+        return;
+    }
+
+    let reason = if !reason.is_empty() {
+        reason
+    } else if escalating {
+        format!(
+            "{} for {} sessions",
+            label(target),
+            alert_state.pending_count
+        )
+    } else {
+        format!(
+            "eased to {} for {} sessions",
+            label(target),
+            alert_state.pending_count
+        )
+    };
+    let signals = if sustained.is_empty() {
+        contributing
+    } else {
+        sustained
+    };
+    alert_state.transitions.push(AlertTransition {
+        timestamp: timestamp.to_string(),
+        from: alert_state.level,
+        to: target,
+        signals: signals.clone(),
+        reason,
+    });
+    if alert_state.transitions.len() > MAX_TRANSITIONS {
+        let excess = alert_state.transitions.len() - MAX_TRANSITIONS;
+        alert_state.transitions.drain(..excess);
+    }
+    alert_state.level = target;
+    alert_state.since = Some(timestamp.to_string());
+    alert_state.signals = signals;
+    alert_state.pending = None;
+    alert_state.pending_count = 0;
+    alert_state.sessions_at_level = 1;
+}
+
+// --- Acknowledgements ---
+
+/// How an acknowledgement stands against the latest analysis.
+#[derive(Debug, PartialEq)]
+pub enum AckStatus {
+    Active,
+    /// The acknowledged condition got worse; the reason says how.
+    Reraised(String),
+    Expired,
+}
+
+pub fn ack_status(ack: &Ack, analysis: &Analysis, config: &Config, now: u64) -> AckStatus {
+    if ack
+        .until
+        .as_deref()
+        .and_then(state::parse_iso_epoch)
+        .is_some_and(|until| until < now)
+    {
+        return AckStatus::Expired;
+    }
+
+    let Some(name) = &ack.signal else {
+        if analysis.alert_level > ack.level {
+            return AckStatus::Reraised(format!(
+                "level rose from {} to {}",
+                label(ack.level),
+                label(analysis.alert_level)
+            ));
+        }
+        return AckStatus::Active;
+    };
+
+    let Some(trend) = analysis.signals.get(name) else {
+        return AckStatus::Active;
+    };
+    let zone_then = ack.zone.clone().unwrap_or(Zone::Healthy);
+    let zone_now = trend.zone.clone().unwrap_or(Zone::Healthy);
+    if zone_now > zone_then {
+        return AckStatus::Reraised(format!(
+            "{name} moved into the {} zone",
+            zone_word(&zone_now)
+        ));
+    }
+    if let (Some(then), Some(now_value), Some(threshold)) =
+        (ack.value, trend.current, config.thresholds.get(name))
+    {
+        if config.polarity_of(name).improvement(then, now_value) < threshold.decline {
+            return AckStatus::Reraised(format!(
+                "{name} worsened from {then:.2} to {now_value:.2}"
+            ));
+        }
+    }
+    AckStatus::Active
+}
+
+/// Drop acks that have lapsed or whose condition has cleared.
+fn prune_acks(alert_state: &mut AlertState, analysis: &Analysis, timestamp: &str) {
+    let now = state::parse_iso_epoch(timestamp).unwrap_or(0);
+    let level = alert_state.level;
+    alert_state.acks.retain(|ack| {
+        let lapsed = ack
+            .until
+            .as_deref()
+            .and_then(state::parse_iso_epoch)
+            .is_some_and(|until| until < now);
+        let cleared = match &ack.signal {
+            None => level == AlertLevel::Healthy,
+            Some(name) => !analysis
+                .signals
+                .get(name)
+                .is_some_and(|t| t.trend == Trend::Worsening || t.zone == Some(Zone::Concern)),
+        };
+        !lapsed && !cleared
+    });
+}
+
+fn zone_word(zone: &Zone) -> &'static str {
+    match zone {
+        Zone::Healthy => "green",
+        Zone::Watch => "yellow",
+        Zone::Concern => "red",
+    }
+}
+
+/// `vigil-echo ack`: acknowledge the current alert or one signal.
+pub fn ack(until: Option<String>, signal: Option<String>, note: String) -> Result<(), String> {
+    if let Some(name) = &signal {
+        if !analyze::SIGNAL_NAMES.contains(&name.as_str()) {
+            return Err(format!(
+                "Unknown signal '{name}' (expected one of: {})",
+                analyze::SIGNAL_NAMES.join(", ")
+            ));
+        }
+    }
+    let until = until
+        .as_deref()
+        .map(|u| state::parse_timestamp_arg(u, true))
+        .transpose()?;
+
+    let mut alert_state = state::load_alert_state()?;
+    let analysis = state::load_analysis()?;
+    let trend = signal
+        .as_ref()
+        .and_then(|name| analysis.as_ref()?.signals.get(name));
+
+    match (&signal, trend) {
+        (None, _) if alert_state.level == AlertLevel::Healthy => {
+            return Err("Nothing to acknowledge: alert level is HEALTHY".to_string());
+        }
+        (Some(name), t)
+            if !t.is_some_and(|t| t.trend == Trend::Worsening || t.zone == Some(Zone::Concern)) =>
+        {
+            return Err(format!(
+                "Nothing to acknowledge: {name} is not worsening or in its red zone"
+            ));
+        }
+        _ => {}
+    }
+
+    let entry = Ack {
+        created: state::now_iso(),
+        until,
+        signal: signal.clone(),
+        note,
+        level: alert_state.level,
+        zone: trend.and_then(|t| t.zone.clone()),
+        value: trend.and_then(|t| t.current),
+    };
+    // One ack per scope; a new one replaces the old
+    alert_state.acks.retain(|a| a.signal != signal);
+    alert_state.acks.push(entry);
+    state::save_alert_state(&alert_state)?;
+
+    let scope = signal.as_deref().unwrap_or("current alert");
+    let until = alert_state
+        .acks
+        .last()
+        .and_then(|a| a.until.as_deref())
+        .map(|u| format!(" until {u}"))
+        .unwrap_or_default();
+    println!("{} Acknowledged {}{}", "✓".green(), scope, until);
+    Ok(())
+}
+
+pub fn label(level: AlertLevel) -> &'static str {
+    match level {
+        AlertLevel::Healthy => "HEALTHY",
+        AlertLevel::Watch => "WATCH",
+        AlertLevel::Concern => "CONCERN",
+        AlertLevel::Alert => "ALERT",
+    }
+}
+
+fn colored_label(level: AlertLevel) -> String {
+    match level {
+        AlertLevel::Healthy => format!("{}", "HEALTHY".green()),
+        AlertLevel::Watch => format!("{}", "WATCH".yellow()),
+        AlertLevel::Concern => format!("{}", "CONCERN".red()),
+        AlertLevel::Alert => format!("{}", "ALERT".red().bold()),
+    }
+}
+
+// --- `alerts` subcommand ---
+
+pub fn run(json_output: bool, limit: usize) -> Result<(), String> {
+    let alert_state = state::load_alert_state()?;
+    let start = alert_state.transitions.len().saturating_sub(limit);
+    let transitions = &alert_state.transitions[start..];
+
+    if json_output {
+        let output = serde_json::json!({
+            "level": alert_state.level,
+            "since": alert_state.since,
+            "signals": alert_state.signals,
+            "pending": alert_state.pending,
+            "pending_count": alert_state.pending_count,
+            "streaks": alert_state.streaks,
+            "acks": alert_state.acks,
+            "transitions": transitions,
+        });
+        let json_str = serde_json::to_string_pretty(&output)
+            .map_err(|e| format!("JSON serialization failed: {e}"))?;
+        println!("{json_str}");
+        return Ok(());
+    }
+
+    println!();
+    println!("  {} — alert history", "vigil-echo".bold());
+    println!();
+    let since = alert_state
+        .since
+        .as_deref()
+        .map(|s| format!(" since {s}"))
+        .unwrap_or_default();
+    println!(
+        "  Current: {}{} ({} sessions)",
+        colored_label(alert_state.level),
+        since,
+        alert_state.sessions_at_level
+    );
+    if !alert_state.signals.is_empty() {
+        println!("  Signals: {}", alert_state.signals.join(", "));
+    }
+    if let Some(pending) = alert_state.pending {
+        println!(
+            "  Pending: {} ({} session{})",
+            colored_label(pending),
+            alert_state.pending_count,
+            if alert_state.pending_count == 1 {
+                ""
+            } else {
+                "s"
+            }
+        );
+    }
+
+    println!();
+    println!("  {}", "Transitions".bold());
+    if transitions.is_empty() {
+        println!("    No transitions yet.");
+    }
+    for t in transitions {
+        let signals = if t.signals.is_empty() {
+            String::new()
+        } else {
+            format!("  [{}]", t.signals.join(", "))
+        };
+        println!(
+            "    {}  {} → {}  {}{}",
+            t.timestamp,
+            colored_label(t.from),
+            colored_label(t.to),
+            t.reason,
+            signals.dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{SignalTrend, Signals};
+    use std::collections::HashMap;
+
+    fn make_vector(i: usize, qg: f64) -> SignalVector {
+        SignalVector {
+            timestamp: format!("2026-03-01T10:{:02}:00Z", i),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(0.7),
+                question_generation: Some(qg),
+                thought_lifecycle: Some(0.5),
+                evidence_grounding: Some(0.6),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn observed(level: AlertLevel) -> Analysis {
+        Analysis {
+            timestamp: String::new(),
+            alert_level: level,
+            signals: HashMap::new(),
+            improving_count: 0,
+            stable_count: 0,
+            worsening_count: 0,
+            highlight: None,
+            watch_messages: Vec::new(),
+            data_points: 10,
+            change_points: Vec::new(),
+            composite_score: None,
+        }
+    }
+
+    #[test]
+    fn escalation_needs_consecutive_sessions() {
+        let config = Config::default();
+        let mut st = AlertState::default();
+        advance(&mut st, &observed(AlertLevel::Watch), "t1", &config);
+        assert_eq!(st.level, AlertLevel::Healthy);
+        assert_eq!(st.pending, Some(AlertLevel::Watch));
+        advance(&mut st, &observed(AlertLevel::Watch), "t2", &config);
+        assert_eq!(st.level, AlertLevel::Watch);
+        assert_eq!(st.since.as_deref(), Some("t2"));
+        assert_eq!(st.transitions.len(), 1);
+    }
+
+    #[test]
+    fn single_healthy_session_does_not_clear() {
+        let config = Config::default();
+        let mut st = AlertState {
+            level: AlertLevel::Watch,
+            ..AlertState::default()
+        };
+        for (i, level) in [
+            AlertLevel::Healthy,
+            AlertLevel::Watch,
+            AlertLevel::Healthy,
+            AlertLevel::Healthy,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            advance(&mut st, &observed(level), &format!("t{i}"), &config);
+            assert_eq!(st.level, AlertLevel::Watch);
+        }
+        advance(&mut st, &observed(AlertLevel::Healthy), "t9", &config);
+        assert_eq!(st.level, AlertLevel::Healthy);
+    }
+
+    #[test]
+    fn step_is_idempotent_per_collection() {
+        let config = Config::default();
+        let mut st = AlertState::default();
+        step(&mut st, &observed(AlertLevel::Watch), "t1", &config);
+        step(&mut st, &observed(AlertLevel::Watch), "t1", &config);
+        assert_eq!(st.pending_count, 1);
+    }
+
+    #[test]
+    fn sustained_single_signal_decline_escalates_to_alert() {
+        let config = Config::default();
+        let mut history: Vec<SignalVector> = (0..7).map(|i| make_vector(i, 20.0)).collect();
+        // Questions keep falling session after session
+        for i in 7..20 {
+            history.push(make_vector(i, 20.0 - (i - 6) as f64));
+        }
+        let levels = replay(&history, &[], &config);
+        assert!(levels.contains(&AlertLevel::Alert));
+        assert!(!levels.contains(&AlertLevel::Concern));
+    }
+
+    #[test]
+    fn held_concern_escalates_to_alert() {
+        let config = Config::default();
+        let mut st = AlertState {
+            level: AlertLevel::Concern,
+            sessions_at_level: 1,
+            ..AlertState::default()
+        };
+        for i in 0..config.alert_after_sessions {
+            advance(
+                &mut st,
+                &observed(AlertLevel::Concern),
+                &format!("t{i}"),
+                &config,
+            );
+        }
+        assert_eq!(st.level, AlertLevel::Alert);
+        assert!(st.transitions[0].reason.contains("CONCERN sustained"));
+    }
+
+    fn ack(signal: Option<&str>, level: AlertLevel, zone: Option<Zone>, value: Option<f64>) -> Ack {
+        Ack {
+            created: "2026-03-01T10:00:00Z".to_string(),
+            until: None,
+            signal: signal.map(str::to_string),
+            note: "looked at it".to_string(),
+            level,
+            zone,
+            value,
+        }
+    }
+
+    fn with_signal(level: AlertLevel, trend: Trend, zone: Zone, current: f64) -> Analysis {
+        let mut analysis = observed(level);
+        analysis.signals.insert(
+            "question_generation".to_string(),
+            SignalTrend {
+                current: Some(current),
+                trend,
+                delta: 0.0,
+                health_delta: 0.0,
+                p_value: None,
+                slope: None,
+                zone: Some(zone),
+                message: Some("question_generation dropped".to_string()),
+            },
+        );
+        analysis
+    }
+
+    #[test]
+    fn general_ack_reraises_when_level_rises() {
+        let config = Config::default();
+        let a = ack(None, AlertLevel::Concern, None, None);
+        assert_eq!(
+            ack_status(&a, &observed(AlertLevel::Concern), &config, 0),
+            AckStatus::Active
+        );
+        assert!(matches!(
+            ack_status(&a, &observed(AlertLevel::Alert), &config, 0),
+            AckStatus::Reraised(_)
+        ));
+    }
+
+    #[test]
+    fn signal_ack_reraises_on_worse_zone_or_value() {
+        let config = Config::default();
+        let a = ack(
+            Some("question_generation"),
+            AlertLevel::Watch,
+            Some(Zone::Watch),
+            Some(5.0),
+        );
+        let same = with_signal(AlertLevel::Watch, Trend::Worsening, Zone::Watch, 5.0);
+        assert_eq!(ack_status(&a, &same, &config, 0), AckStatus::Active);
+        let red = with_signal(AlertLevel::Watch, Trend::Worsening, Zone::Concern, 5.0);
+        assert!(matches!(
+            ack_status(&a, &red, &config, 0),
+            AckStatus::Reraised(_)
+        ));
+        let lower = with_signal(AlertLevel::Watch, Trend::Worsening, Zone::Watch, 1.0);
+        assert!(matches!(
+            ack_status(&a, &lower, &config, 0),
+            AckStatus::Reraised(_)
+        ));
+    }
+
+    #[test]
+    fn acks_expire_and_clear() {
+        let config = Config::default();
+        let mut snoozed = ack(None, AlertLevel::Watch, None, None);
+        snoozed.until = Some("2026-03-02T23:59:59Z".to_string());
+        let late = state::parse_iso_epoch("2026-03-03T00:00:00Z").unwrap();
+        assert_eq!(
+            ack_status(&snoozed, &observed(AlertLevel::Watch), &config, late),
+            AckStatus::Expired
+        );
+
+        let mut st = AlertState {
+            level: AlertLevel::Watch,
+            acks: vec![ack(
+                Some("question_generation"),
+                AlertLevel::Watch,
+                Some(Zone::Watch),
+                Some(5.0),
+            )],
+            ..AlertState::default()
+        };
+        let still_bad = with_signal(AlertLevel::Watch, Trend::Worsening, Zone::Watch, 5.0);
+        advance(&mut st, &still_bad, "2026-03-01T11:00:00Z", &config);
+        assert_eq!(st.acks.len(), 1);
+        let recovered = with_signal(AlertLevel::Watch, Trend::Stable, Zone::Healthy, 8.0);
+        advance(&mut st, &recovered, "2026-03-01T12:00:00Z", &config);
+        assert!(st.acks.is_empty());
+    }
+}
@@ -1,23 +1,29 @@
 use std::collections::HashMap;
 
-use crate::state::{AlertLevel, Analysis, Config, SignalTrend, SignalVector, Trend};
+use crate::state::{
+    AlertLevel, Analysis, ChangePoint, Config, Polarity, SignalTrend, SignalVector, ThresholdMode,
+    ThresholdPair, Trend, TrendMethod, Zone,
+};
+use crate::stats;
 
-const SIGNAL_NAMES: &[&str] = &[
+pub const SIGNAL_NAMES: &[&str] = &[
     "vocabulary_diversity",
     "question_generation",
     "thought_lifecycle",
     "evidence_grounding",
+    "hedging_balance",
+    "question_depth",
+    "questions_opened",
+    "questions_resolved",
+    "questions_abandoned",
+    "open_question_age",
+    "thought_resolution_time",
+    "stale_thoughts",
 ];
 
 /// Extract a signal value by name from a SignalVector.
-fn get_signal(sv: &SignalVector, name: &str) -> Option<f64> {
-    match name {
-        "vocabulary_diversity" => sv.signals.vocabulary_diversity,
-        "question_generation" => sv.signals.question_generation,
-        "thought_lifecycle" => sv.signals.thought_lifecycle,
-        "evidence_grounding" => sv.signals.evidence_grounding,
-        _ => None,
-    }
+pub fn get_signal(sv: &SignalVector, name: &str) -> Option<f64> {
+    sv.signals.get(name)
 }
 
 /// Compute mean of values, skipping None.
@@ -29,30 +35,51 @@ fn mean(values: &[Option<f64>]) -> Option<f64> {
     Some(valid.iter().sum::<f64>() / valid.len() as f64)
 }
 
-/// Run trend analysis on signal history.
-pub fn run(history: &[SignalVector], config: &Config) -> Analysis {
-    let window = config.window_size.min(history.len());
-    let data = &history[history.len().saturating_sub(window)..];
+/// Run trend analysis on signal history. Trend windows never reach back past
+/// the latest baseline reset, so a deliberate change isn't compared across;
+/// change points still cover the full history.
+pub fn run(history: &[SignalVector], resets: &[String], config: &Config) -> Analysis {
+    let baseline = baseline_start(history, resets);
+    let start = history
+        .len()
+        .saturating_sub(config.window_size)
+        .max(baseline);
+    let data = &history[start..];
 
     let mut signal_trends: HashMap<String, SignalTrend> = HashMap::new();
     let mut improving = 0;
     let mut stable = 0;
-    let mut declining = 0;
+    let mut worsening = 0;
+    let mut red_zone = 0;
     let mut watch_messages: Vec<String> = Vec::new();
-    let mut best_delta: Option<(String, f64)> = None;
+    let mut best_delta: Option<(String, f64, f64)> = None;
 
     for &name in SIGNAL_NAMES {
         let values: Vec<Option<f64>> = data.iter().map(|sv| get_signal(sv, name)).collect();
 
+        let current = values.last().and_then(|v| *v);
+        let zone = current.and_then(|v| config.zones.get(name).map(|b| b.zone(v)));
+
         // Need at least 3 data points for trend detection
         if values.len() < 3 {
-            if let Some(current) = values.last().and_then(|v| *v) {
+            if let Some(current) = current {
+                let mut message = None;
+                if zone == Some(Zone::Concern) {
+                    red_zone += 1;
+                    message = Some(zone_message(name, current, config));
+                }
+                watch_messages.extend(message.clone());
                 signal_trends.insert(
                     name.to_string(),
                     SignalTrend {
                         current: Some(current),
                         trend: Trend::Stable,
                         delta: 0.0,
+                        health_delta: 0.0,
+                        p_value: None,
+                        slope: None,
+                        zone,
+                        message,
                     },
                 );
                 stable += 1;
@@ -61,147 +88,335 @@ pub fn run(history: &[SignalVector], config: &Config) -> Analysis {
         }
 
         let recent_start = values.len().saturating_sub(3);
-        let recent = &values[recent_start..];
-        let baseline = &values[..recent_start];
-
-        let recent_mean = mean(recent);
-        let baseline_mean = if baseline.is_empty() {
-            recent_mean
-        } else {
-            mean(baseline)
-        };
+        let baseline_values: Vec<f64> = values[..recent_start].iter().filter_map(|v| *v).collect();
+        let threshold = effective_thresholds(name, &baseline_values, config);
 
-        let current = values.last().and_then(|v| *v);
+        // Robust trend statistics, computed for every method
+        let points: Vec<(f64, f64)> = values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|v| (i as f64, v)))
+            .collect();
+        let series: Vec<f64> = points.iter().map(|(_, v)| *v).collect();
+        let p_value = stats::mann_kendall(&series).map(|(_, p)| p);
+        let slope = stats::theil_sen_slope(&points);
 
-        let (trend, delta) =
-            match (recent_mean, baseline_mean) {
-                (Some(r), Some(b)) => {
-                    let d = r - b;
-                    let threshold = config.thresholds.get(name).cloned().unwrap_or(
-                        crate::state::ThresholdPair {
-                            decline: -0.05,
-                            improve: 0.05,
-                        },
-                    );
-                    if d < threshold.decline {
-                        (Trend::Declining, d)
-                    } else if d > threshold.improve {
-                        (Trend::Improving, d)
-                    } else {
-                        (Trend::Stable, d)
-                    }
-                }
-                _ => (Trend::Stable, 0.0),
-            };
+        let levels = match config.trend_method {
+            TrendMethod::MeanDelta => mean_delta_levels(&values, config).map(|(b, r)| (b, r, true)),
+            TrendMethod::MannKendall => {
+                mann_kendall_levels(&values, &points, p_value, slope, config)
+            }
+        };
 
+        // Classify on the health-oriented delta so polarity decides what "worse" means
+        let polarity = config.polarity_of(name);
+        let (trend, delta, health_delta) = match levels {
+            Some((before, after, significant)) => {
+                let health_delta = polarity.improvement(before, after);
+                let trend = if significant {
+                    classify(health_delta, &threshold)
+                } else {
+                    Trend::Stable
+                };
+                (trend, after - before, health_delta)
+            }
+            None => (Trend::Stable, 0.0, 0.0),
+        };
+
+        let mut message = None;
         match trend {
             Trend::Improving => {
                 improving += 1;
-                if best_delta.as_ref().is_none_or(|(_, bd)| delta > *bd) {
-                    best_delta = Some((name.to_string(), delta));
+                if best_delta
+                    .as_ref()
+                    .is_none_or(|(_, _, bh)| health_delta > *bh)
+                {
+                    best_delta = Some((name.to_string(), delta, health_delta));
                 }
             }
-            Trend::Declining => {
-                declining += 1;
-                let msg = decline_message(name, current, delta);
-                watch_messages.push(msg);
+            Trend::Worsening => {
+                worsening += 1;
+                message = Some(decline_message(name, &polarity, current, delta));
             }
             Trend::Stable => stable += 1,
         }
 
+        // A signal sitting in its red zone counts even when its trend is flat
+        if trend != Trend::Worsening && zone == Some(Zone::Concern) {
+            red_zone += 1;
+            if let Some(v) = current {
+                message = Some(zone_message(name, v, config));
+            }
+        }
+        watch_messages.extend(message.clone());
+
         signal_trends.insert(
             name.to_string(),
             SignalTrend {
                 current,
                 trend,
                 delta,
+                health_delta,
+                p_value,
+                slope,
+                zone,
+                message,
             },
         );
     }
 
-    // Determine alert level
-    let alert_level = if declining >= 3 {
+    // Observed alert level; escalation to ALERT and hysteresis live in the
+    // persisted alert state machine (see alerts.rs)
+    let flagged = worsening + red_zone;
+    let alert_level = if flagged >= 3 {
         AlertLevel::Concern
-    } else if declining >= 1 {
+    } else if flagged >= 1 {
         AlertLevel::Watch
     } else {
         AlertLevel::Healthy
     };
 
-    // Check for sustained decline (ALERT level)
-    let alert_level = if alert_level == AlertLevel::Concern
-        && history.len() >= config.alert_after_sessions
-    {
-        // Check if decline has persisted across many sessions
-        let lookback = config.alert_after_sessions.min(history.len());
-        let old_data = &history[history.len() - lookback..];
-        let sustained = SIGNAL_NAMES.iter().any(|name| {
-            let vals: Vec<Option<f64>> = old_data.iter().map(|sv| get_signal(sv, name)).collect();
-            if vals.len() < 4 {
-                return false;
+    let highlight = best_delta.map(|(name, delta, _)| {
+        let friendly = friendly_name(&name);
+        match config.polarity_of(&name) {
+            Polarity::HigherIsBetter => format!("{} trending up ({:+.2})", friendly, delta),
+            Polarity::LowerIsBetter => format!("{} trending down ({:+.2})", friendly, delta),
+            Polarity::TargetRange { .. } => {
+                format!("{} moving into range ({:+.2})", friendly, delta)
             }
-            let first_half = mean(&vals[..vals.len() / 2]);
-            let second_half = mean(&vals[vals.len() / 2..]);
-            matches!((first_half, second_half), (Some(f), Some(s)) if s < f - 0.1)
-        });
-        if sustained {
-            AlertLevel::Alert
-        } else {
-            AlertLevel::Concern
         }
-    } else {
-        alert_level
-    };
-
-    let highlight = best_delta.map(|(name, delta)| {
-        let friendly = friendly_name(&name);
-        format!("{} trending up (+{:.2})", friendly, delta)
     });
 
+    let change_points = detect_change_points(history, config);
+    let composite = history.last().and_then(|sv| composite_score(sv, config));
+
     Analysis {
         timestamp: crate::state::now_iso(),
         alert_level,
         signals: signal_trends,
         improving_count: improving,
         stable_count: stable,
-        declining_count: declining,
+        worsening_count: worsening,
         highlight,
         watch_messages,
-        data_points: history.len(),
+        data_points: history.len() - baseline,
+        change_points,
+        composite_score: composite,
+    }
+}
+
+/// Index of the first collection at or after the latest reset. Resets later
+/// than the newest collection take effect from the next one.
+pub fn baseline_start(history: &[SignalVector], resets: &[String]) -> usize {
+    let Some(last) = history
+        .last()
+        .and_then(|sv| crate::state::parse_iso_epoch(&sv.timestamp))
+    else {
+        return 0;
+    };
+    let latest = resets
+        .iter()
+        .filter_map(|r| crate::state::parse_iso_epoch(r))
+        .filter(|&r| r <= last)
+        .max();
+    match latest {
+        Some(reset) => history
+            .iter()
+            .position(|sv| crate::state::parse_iso_epoch(&sv.timestamp).is_some_and(|t| t >= reset))
+            .unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// Weighted 0–100 health score for one collection, from each signal's
+/// position between its health zones. Signals without a value, zone cutoffs
+/// or a positive weight are left out.
+pub fn composite_score(sv: &SignalVector, config: &Config) -> Option<f64> {
+    let mut weighted = 0.0;
+    let mut total_weight = 0.0;
+    for &name in SIGNAL_NAMES {
+        let weight = config.weights.get(name).copied().unwrap_or(1.0);
+        if weight <= 0.0 {
+            continue;
+        }
+        let position =
+            get_signal(sv, name).and_then(|v| config.zones.get(name).and_then(|b| b.position(v)));
+        if let Some(position) = position {
+            weighted += weight * position;
+            total_weight += weight;
+        }
+    }
+    if total_weight > 0.0 {
+        Some(100.0 * weighted / total_weight)
+    } else {
+        None
+    }
+}
+
+/// Run CUSUM over each signal's full series, mapping hits back to history indices.
+pub fn detect_change_points(history: &[SignalVector], config: &Config) -> Vec<ChangePoint> {
+    let mut change_points = Vec::new();
+    for &name in SIGNAL_NAMES {
+        let (indices, series): (Vec<usize>, Vec<f64>) = history
+            .iter()
+            .enumerate()
+            .filter_map(|(i, sv)| get_signal(sv, name).map(|v| (i, v)))
+            .unzip();
+        for (pos, shift) in
+            stats::cusum_change_points(&series, config.change_point_k, config.change_point_h)
+        {
+            let index = indices[pos];
+            change_points.push(ChangePoint {
+                signal: name.to_string(),
+                index,
+                timestamp: history[index].timestamp.clone(),
+                shift,
+            });
+        }
+    }
+    change_points.sort_by_key(|cp| cp.index);
+    change_points
+}
+
+/// Decline/improve bands for a signal. Fixed mode reads them from config;
+/// adaptive mode scales them to the signal's own volatility over the baseline
+/// part of the window, falling back to the fixed pair while there is too
+/// little (or too flat) data to estimate it.
+fn effective_thresholds(name: &str, baseline: &[f64], config: &Config) -> ThresholdPair {
+    let fixed = config
+        .thresholds
+        .get(name)
+        .cloned()
+        .unwrap_or(ThresholdPair {
+            decline: -0.05,
+            improve: 0.05,
+        });
+    match config.threshold_mode {
+        ThresholdMode::Fixed => fixed,
+        ThresholdMode::Adaptive => match stats::std_dev(baseline) {
+            Some(sd) if sd > f64::EPSILON => ThresholdPair {
+                decline: -config.adaptive_k * sd,
+                improve: config.adaptive_k * sd,
+            },
+            _ => fixed,
+        },
     }
 }
 
-fn decline_message(name: &str, current: Option<f64>, delta: f64) -> String {
+/// Last 3 points vs the rest of the window, as (baseline, recent) levels.
+/// The baseline is a plain mean in fixed mode and an exponentially weighted
+/// mean in adaptive mode.
+fn mean_delta_levels(values: &[Option<f64>], config: &Config) -> Option<(f64, f64)> {
+    let recent_start = values.len().saturating_sub(3);
+    let recent = &values[recent_start..];
+    let baseline = &values[..recent_start];
+
+    let recent_mean = mean(recent)?;
+    let baseline_mean = if baseline.is_empty() {
+        recent_mean
+    } else {
+        match config.threshold_mode {
+            ThresholdMode::Fixed => mean(baseline)?,
+            ThresholdMode::Adaptive => {
+                let valid: Vec<f64> = baseline.iter().filter_map(|v| *v).collect();
+                stats::ewma(&valid, config.ewma_alpha)?
+            }
+        }
+    };
+    Some((baseline_mean, recent_mean))
+}
+
+/// Theil-Sen slope projected across the window, ending at the recent mean,
+/// as (start, end, significant) levels. Mann-Kendall significance gates the
+/// trend; the projected change must still clear the thresholds.
+fn mann_kendall_levels(
+    values: &[Option<f64>],
+    points: &[(f64, f64)],
+    p_value: Option<f64>,
+    slope: Option<f64>,
+    config: &Config,
+) -> Option<(f64, f64, bool)> {
+    let p = p_value?;
+    let slope = slope?;
+    let span = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => last.0 - first.0,
+        _ => 0.0,
+    };
+    let end = mean(&values[values.len().saturating_sub(3)..])?;
+    Some((end - slope * span, end, p < config.trend_significance))
+}
+
+fn classify(delta: f64, threshold: &ThresholdPair) -> Trend {
+    if delta < threshold.decline {
+        Trend::Worsening
+    } else if delta > threshold.improve {
+        Trend::Improving
+    } else {
+        Trend::Stable
+    }
+}
+
+fn decline_message(name: &str, polarity: &Polarity, current: Option<f64>, delta: f64) -> String {
     let val = current
         .map(|v| format!("{:.2}", v))
         .unwrap_or("?".to_string());
-    match name {
-        "vocabulary_diversity" => format!(
+    match (name, polarity) {
+        ("vocabulary_diversity", Polarity::HigherIsBetter) => format!(
             "vocabulary_diversity at {} ({:+.2}) — reflections reusing the same words",
             val, delta
         ),
-        "question_generation" => format!(
+        ("question_generation", Polarity::HigherIsBetter) => format!(
             "question_generation at {} ({:+.0}) — fewer new questions being asked",
             val, delta
         ),
-        "thought_lifecycle" => format!(
+        ("thought_lifecycle", Polarity::HigherIsBetter) => format!(
             "thought_lifecycle at {} ({:+.2}) — thoughts accumulating without resolution",
             val, delta
         ),
-        "evidence_grounding" => format!(
+        ("evidence_grounding", Polarity::HigherIsBetter) => format!(
             "evidence_grounding at {} ({:+.2}) — conclusions drifting from concrete inputs",
             val, delta
         ),
-        _ => format!("{} at {} ({:+.2})", name, val, delta),
+        (_, Polarity::HigherIsBetter) => format!("{} at {} ({:+.2}) — falling", name, val, delta),
+        (_, Polarity::LowerIsBetter) => format!("{} at {} ({:+.2}) — rising", name, val, delta),
+        (_, Polarity::TargetRange { low, high }) => format!(
+            "{} at {} ({:+.2}) — drifting out of its {:.2}–{:.2} range",
+            name, val, delta, low, high
+        ),
     }
 }
 
-fn friendly_name(name: &str) -> &str {
+fn zone_message(name: &str, value: f64, config: &Config) -> String {
+    let cutoff = config
+        .zones
+        .get(name)
+        .and_then(|b| match (b.concern_below, b.concern_above) {
+            (Some(c), _) if value < c => Some(format!("below {c:.2}")),
+            (_, Some(c)) if value > c => Some(format!("above {c:.2}")),
+            _ => None,
+        });
+    format!(
+        "{} at {:.2} — in the red zone ({})",
+        name,
+        value,
+        cutoff.unwrap_or("?".to_string())
+    )
+}
+
+pub fn friendly_name(name: &str) -> &str {
     match name {
         "vocabulary_diversity" => "vocabulary diversity",
         "question_generation" => "question generation",
         "thought_lifecycle" => "thought lifecycle",
         "evidence_grounding" => "evidence grounding",
+        "hedging_balance" => "hedging balance",
+        "question_depth" => "question depth",
+        "questions_opened" => "questions opened",
+        "questions_resolved" => "questions resolved",
+        "questions_abandoned" => "questions abandoned",
+        "open_question_age" => "open question age",
+        "thought_resolution_time" => "thought resolution time",
+        "stale_thoughts" => "stale thoughts",
         _ => name,
     }
 }
@@ -220,8 +435,10 @@ mod tests {
                 question_generation: Some(qg),
                 thought_lifecycle: Some(tl),
                 evidence_grounding: Some(eg),
+                ..Signals::default()
             },
             document_hashes: HashMap::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -229,13 +446,13 @@ mod tests {
     fn healthy_with_stable_signals() {
         let history: Vec<SignalVector> = (0..5).map(|_| make_vector(0.7, 5.0, 0.5, 0.6)).collect();
         let config = Config::default();
-        let analysis = run(&history, &config);
+        let analysis = run(&history, &[], &config);
         assert_eq!(analysis.alert_level, AlertLevel::Healthy);
-        assert_eq!(analysis.declining_count, 0);
+        assert_eq!(analysis.worsening_count, 0);
     }
 
     #[test]
-    fn watch_with_one_declining() {
+    fn watch_with_one_worsening() {
         let mut history: Vec<SignalVector> = Vec::new();
         // Baseline: high vocabulary diversity
         for _ in 0..7 {
@@ -246,16 +463,271 @@ mod tests {
             history.push(make_vector(0.5, 5.0, 0.5, 0.6));
         }
         let config = Config::default();
-        let analysis = run(&history, &config);
+        let analysis = run(&history, &[], &config);
+        assert_eq!(analysis.alert_level, AlertLevel::Watch);
+        assert!(analysis.worsening_count >= 1);
+    }
+
+    #[test]
+    fn baseline_reset_stops_cross_regime_comparison() {
+        let mut history: Vec<SignalVector> = Vec::new();
+        for i in 0..10 {
+            let vd = if i < 7 { 0.8 } else { 0.5 };
+            let mut sv = make_vector(vd, 5.0, 0.5, 0.6);
+            sv.timestamp = format!("2026-03-01T10:{:02}:00Z", i);
+            history.push(sv);
+        }
+        let config = Config::default();
+        let resets = vec!["2026-03-01T10:06:30Z".to_string()];
+        assert_eq!(baseline_start(&history, &resets), 7);
+        let analysis = run(&history, &resets, &config);
+        assert_eq!(
+            analysis.signals["vocabulary_diversity"].trend,
+            Trend::Stable
+        );
+        assert_eq!(analysis.data_points, 3);
+        // A reset after the newest collection waits for the next one
+        assert_eq!(
+            baseline_start(&history, &["2026-03-02T00:00:00Z".to_string()]),
+            0
+        );
+    }
+
+    #[test]
+    fn mann_kendall_ignores_single_odd_session() {
+        let mut history: Vec<SignalVector> = (0..9)
+            .map(|i| make_vector(0.7 + (i % 2) as f64 * 0.01, 5.0, 0.5, 0.6))
+            .collect();
+        history.push(make_vector(0.3, 5.0, 0.5, 0.6));
+        let config = Config {
+            trend_method: TrendMethod::MannKendall,
+            ..Config::default()
+        };
+        let analysis = run(&history, &[], &config);
+        let vd = &analysis.signals["vocabulary_diversity"];
+        assert_eq!(vd.trend, Trend::Stable);
+        assert!(vd.p_value.unwrap() > 0.05);
+        assert_eq!(analysis.alert_level, AlertLevel::Healthy);
+
+        // The mean-delta method flags the same history
+        let analysis = run(&history, &[], &Config::default());
         assert_eq!(analysis.alert_level, AlertLevel::Watch);
-        assert!(analysis.declining_count >= 1);
+    }
+
+    #[test]
+    fn mann_kendall_detects_steady_decline() {
+        let history: Vec<SignalVector> = (0..10)
+            .map(|i| make_vector(0.8 - i as f64 * 0.03, 5.0, 0.5, 0.6))
+            .collect();
+        let config = Config {
+            trend_method: TrendMethod::MannKendall,
+            ..Config::default()
+        };
+        let analysis = run(&history, &[], &config);
+        let vd = &analysis.signals["vocabulary_diversity"];
+        assert_eq!(vd.trend, Trend::Worsening);
+        assert!((vd.slope.unwrap() + 0.03).abs() < 1e-9);
+        assert!((vd.delta + 0.27).abs() < 1e-9);
+    }
+
+    #[test]
+    fn adaptive_thresholds_follow_signal_noise() {
+        // Noisy questions: swings of ±2 are normal, so a 1.5 drop isn't a decline
+        let mut history: Vec<SignalVector> = (0..7)
+            .map(|i| make_vector(0.7, if i % 2 == 0 { 3.0 } else { 7.0 }, 0.5, 0.6))
+            .collect();
+        for _ in 0..3 {
+            history.push(make_vector(0.7, 3.5, 0.5, 0.6));
+        }
+        let fixed = run(&history, &[], &Config::default());
+        assert_eq!(fixed.signals["question_generation"].trend, Trend::Worsening);
+
+        let config = Config {
+            threshold_mode: ThresholdMode::Adaptive,
+            ..Config::default()
+        };
+        let adaptive = run(&history, &[], &config);
+        assert_eq!(adaptive.signals["question_generation"].trend, Trend::Stable);
+    }
+
+    #[test]
+    fn adaptive_thresholds_tighten_for_steady_signal() {
+        // Steady grounding: a 0.06 drop is well outside its usual ±0.01
+        let mut history: Vec<SignalVector> = (0..7)
+            .map(|i| make_vector(0.7, 5.0, 0.5, 0.6 + (i % 2) as f64 * 0.02))
+            .collect();
+        for _ in 0..3 {
+            history.push(make_vector(0.7, 5.0, 0.5, 0.55));
+        }
+        let fixed = run(&history, &[], &Config::default());
+        assert_eq!(fixed.signals["evidence_grounding"].trend, Trend::Stable);
+
+        let config = Config {
+            threshold_mode: ThresholdMode::Adaptive,
+            ..Config::default()
+        };
+        let adaptive = run(&history, &[], &config);
+        assert_eq!(
+            adaptive.signals["evidence_grounding"].trend,
+            Trend::Worsening
+        );
+    }
+
+    #[test]
+    fn change_point_records_index_and_timestamp() {
+        let mut history: Vec<SignalVector> = Vec::new();
+        for i in 0..8 {
+            history.push(make_vector(0.7 + (i % 2) as f64 * 0.01, 5.0, 0.5, 0.6));
+        }
+        for i in 0..6 {
+            history.push(make_vector(0.7 + (i % 2) as f64 * 0.01, 2.0, 0.5, 0.6));
+        }
+        history[8].timestamp = "2026-03-01T09:00:00Z".to_string();
+        let analysis = run(&history, &[], &Config::default());
+        assert_eq!(analysis.change_points.len(), 1);
+        let cp = &analysis.change_points[0];
+        assert_eq!(cp.signal, "question_generation");
+        assert_eq!(cp.index, 8);
+        assert_eq!(cp.timestamp, "2026-03-01T09:00:00Z");
+        assert!((cp.shift + 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn red_zone_raises_watch_with_flat_trend() {
+        let history: Vec<SignalVector> = (0..5).map(|_| make_vector(0.7, 1.0, 0.5, 0.6)).collect();
+        let analysis = run(&history, &[], &Config::default());
+        let qg = &analysis.signals["question_generation"];
+        assert_eq!(qg.trend, Trend::Stable);
+        assert_eq!(qg.zone, Some(Zone::Concern));
+        assert_eq!(analysis.alert_level, AlertLevel::Watch);
+        assert!(analysis.watch_messages[0].contains("red zone"));
+    }
+
+    #[test]
+    fn zones_come_from_config() {
+        let history: Vec<SignalVector> = (0..5).map(|_| make_vector(0.7, 1.0, 0.5, 0.6)).collect();
+        let mut config = Config::default();
+        config.zones.insert(
+            "question_generation".to_string(),
+            crate::state::ZoneBounds {
+                concern_below: Some(0.5),
+                watch_below: Some(2.0),
+                ..Default::default()
+            },
+        );
+        let analysis = run(&history, &[], &config);
+        assert_eq!(
+            analysis.signals["question_generation"].zone,
+            Some(Zone::Watch)
+        );
+        assert_eq!(analysis.alert_level, AlertLevel::Healthy);
+    }
+
+    #[test]
+    fn lower_is_better_rise_is_worsening() {
+        let mut history: Vec<SignalVector> =
+            (0..7).map(|_| make_vector(0.5, 5.0, 0.5, 0.6)).collect();
+        for _ in 0..3 {
+            history.push(make_vector(0.7, 5.0, 0.5, 0.6));
+        }
+        let mut config = Config::default();
+        config
+            .polarity
+            .insert("vocabulary_diversity".to_string(), Polarity::LowerIsBetter);
+        let analysis = run(&history, &[], &config);
+        let vd = &analysis.signals["vocabulary_diversity"];
+        assert_eq!(vd.trend, Trend::Worsening);
+        assert!(vd.delta > 0.0);
+        assert!(vd.health_delta < 0.0);
+        assert_eq!(analysis.worsening_count, 1);
+        assert!(analysis.highlight.is_none());
+        assert!(analysis.watch_messages[0].contains("rising"));
+    }
+
+    #[test]
+    fn lower_is_better_drop_is_highlighted() {
+        let mut history: Vec<SignalVector> =
+            (0..7).map(|_| make_vector(0.7, 5.0, 0.5, 0.6)).collect();
+        for _ in 0..3 {
+            history.push(make_vector(0.5, 5.0, 0.5, 0.6));
+        }
+        let mut config = Config::default();
+        config
+            .polarity
+            .insert("vocabulary_diversity".to_string(), Polarity::LowerIsBetter);
+        let analysis = run(&history, &[], &config);
+        assert_eq!(
+            analysis.signals["vocabulary_diversity"].trend,
+            Trend::Improving
+        );
+        assert_eq!(analysis.alert_level, AlertLevel::Healthy);
+        assert!(analysis.highlight.unwrap().contains("trending down"));
+    }
+
+    #[test]
+    fn target_range_flags_both_directions() {
+        let mut config = Config::default();
+        config.polarity.insert(
+            "question_generation".to_string(),
+            Polarity::TargetRange {
+                low: 3.0,
+                high: 6.0,
+            },
+        );
+        for recent in [1.0, 9.0] {
+            let mut history: Vec<SignalVector> =
+                (0..7).map(|_| make_vector(0.7, 5.0, 0.5, 0.6)).collect();
+            for _ in 0..3 {
+                history.push(make_vector(0.7, recent, 0.5, 0.6));
+            }
+            let analysis = run(&history, &[], &config);
+            assert_eq!(
+                analysis.signals["question_generation"].trend,
+                Trend::Worsening
+            );
+        }
+
+        // Moving around inside the range is neither better nor worse
+        let mut history: Vec<SignalVector> =
+            (0..7).map(|_| make_vector(0.7, 3.5, 0.5, 0.6)).collect();
+        for _ in 0..3 {
+            history.push(make_vector(0.7, 5.5, 0.5, 0.6));
+        }
+        let analysis = run(&history, &[], &config);
+        assert_eq!(analysis.signals["question_generation"].trend, Trend::Stable);
+    }
+
+    #[test]
+    fn composite_score_weights_signals() {
+        // vd healthy (1.0), qg on its red cutoff (0.5), tl and eg healthy
+        let sv = make_vector(0.7, 2.0, 0.5, 0.6);
+        let config = Config::default();
+        assert_eq!(composite_score(&sv, &config), Some(87.5));
+
+        let mut config = Config::default();
+        config
+            .weights
+            .insert("question_generation".to_string(), 5.0);
+        assert_eq!(composite_score(&sv, &config), Some(100.0 * 5.5 / 8.0));
+
+        config
+            .weights
+            .insert("question_generation".to_string(), 0.0);
+        assert_eq!(composite_score(&sv, &config), Some(100.0));
+    }
+
+    #[test]
+    fn analysis_stores_composite_of_latest() {
+        let history: Vec<SignalVector> = (0..4).map(|_| make_vector(0.7, 5.0, 0.5, 0.6)).collect();
+        let analysis = run(&history, &[], &Config::default());
+        assert_eq!(analysis.composite_score, Some(100.0));
     }
 
     #[test]
     fn too_few_datapoints() {
         let history = vec![make_vector(0.7, 5.0, 0.5, 0.6)];
         let config = Config::default();
-        let analysis = run(&history, &config);
+        let analysis = run(&history, &[], &config);
         // With only 1 point, everything stable
         assert_eq!(analysis.alert_level, AlertLevel::Healthy);
     }
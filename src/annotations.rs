@@ -0,0 +1,96 @@
+use owo_colors::OwoColorize;
+
+use crate::state::{self, Annotation, SignalVector};
+
+/// `vigil-echo annotate`: record a known event on the timeline.
+pub fn run(note: &str, at: Option<&str>, reset_baseline: bool) -> Result<(), String> {
+    let note = note.trim();
+    if note.is_empty() {
+        return Err("Annotation note is empty".to_string());
+    }
+    let timestamp = match at {
+        Some(at) => state::parse_timestamp_arg(at, false)?,
+        None => state::now_iso(),
+    };
+
+    let mut annotations = state::load_annotations()?;
+    annotations.push(Annotation {
+        timestamp: timestamp.clone(),
+        note: note.to_string(),
+        reset_baseline,
+    });
+    annotations.sort_by_key(|a| state::parse_iso_epoch(&a.timestamp));
+    state::save_annotations(&annotations)?;
+
+    println!("{} Annotated {timestamp}: {note}", "✓".green());
+    if reset_baseline {
+        println!("  Trend baselines restart from the first collection at or after this point.");
+    }
+    Ok(())
+}
+
+/// Timestamps of annotations that reset the trend baseline.
+pub fn resets(annotations: &[Annotation]) -> Vec<String> {
+    annotations
+        .iter()
+        .filter(|a| a.reset_baseline)
+        .map(|a| a.timestamp.clone())
+        .collect()
+}
+
+/// Index of the first collection at or after an annotation, if any.
+pub fn collection_index(history: &[SignalVector], annotation: &Annotation) -> Option<usize> {
+    let at = state::parse_iso_epoch(&annotation.timestamp)?;
+    history
+        .iter()
+        .position(|sv| state::parse_iso_epoch(&sv.timestamp).is_some_and(|t| t >= at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(ts: &str) -> SignalVector {
+        SignalVector {
+            timestamp: ts.to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(0.5),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn annotation(ts: &str, reset_baseline: bool) -> Annotation {
+        Annotation {
+            timestamp: ts.to_string(),
+            note: "note".to_string(),
+            reset_baseline,
+        }
+    }
+
+    #[test]
+    fn annotations_map_to_the_next_collection() {
+        let history = vec![
+            make_vector("2026-03-01T10:00:00Z"),
+            make_vector("2026-03-02T10:00:00Z"),
+        ];
+        let a = annotation("2026-03-01T12:00:00Z", false);
+        assert_eq!(collection_index(&history, &a), Some(1));
+        let late = annotation("2026-03-03T00:00:00Z", false);
+        assert_eq!(collection_index(&history, &late), None);
+    }
+
+    #[test]
+    fn only_reset_annotations_are_resets() {
+        let annotations = vec![
+            annotation("2026-03-01T12:00:00Z", false),
+            annotation("2026-03-02T12:00:00Z", true),
+        ];
+        assert_eq!(resets(&annotations), vec!["2026-03-02T12:00:00Z"]);
+    }
+}
@@ -0,0 +1,267 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use owo_colors::OwoColorize;
+
+use crate::collect::{self, DOCUMENT_FILES};
+use crate::signals::EvidenceMatcher;
+use crate::state::{self, DocumentSnapshot, SignalVector};
+use crate::{alerts, analyze, epochs, paths};
+
+/// One commit that touched the identity documents.
+#[derive(Debug, PartialEq)]
+struct Commit {
+    hash: String,
+    time: u64,
+}
+
+/// `vigil-echo backfill --git <repo>`: seed history from the documents' git log.
+pub fn run(repo: &Path) -> Result<(), String> {
+    let top = PathBuf::from(git(repo, &["rev-parse", "--show-toplevel"])?.trim());
+    let prefix = docs_prefix(&top, &paths::docs_dir()?);
+    let files: Vec<String> = DOCUMENT_FILES
+        .iter()
+        .map(|f| format!("{prefix}{f}"))
+        .collect();
+
+    let mut args = vec!["log", "--reverse", "--format=%H %ct", "--"];
+    args.extend(files.iter().map(String::as_str));
+    let commits = parse_log(&git(&top, &args)?);
+    if commits.is_empty() {
+        return Err(format!(
+            "No commits in {} touch {}",
+            top.display(),
+            files.join(", ")
+        ));
+    }
+
+    let config = state::load_config()?;
+    let mut history = state::load_signals()?;
+    let mut snapshots = state::load_snapshots()?;
+    let evidence = EvidenceMatcher::new(&config.evidence, Some(&paths::docs_dir()?))?;
+
+    // Only commits older than the first collection, so reruns add nothing
+    let first = history
+        .first()
+        .and_then(|sv| state::parse_iso_epoch(&sv.timestamp));
+    let (mut older, overlapping): (Vec<&Commit>, Vec<&Commit>) = commits
+        .iter()
+        .partition(|c| first.is_none_or(|f| c.time < f));
+
+    // Backfill only into the room left under max_history; prepending more
+    // would just have the oldest vectors trimmed straight away
+    let room = config.max_history.saturating_sub(history.len());
+    if room == 0 && !older.is_empty() {
+        return Err(format!(
+            "History already holds {} collections (max_history {}); raise max_history in config.json to backfill {} older commit(s)",
+            history.len(),
+            config.max_history,
+            older.len()
+        ));
+    }
+    let no_room = older.len().saturating_sub(room);
+    older.drain(..no_room);
+
+    let mut vectors: Vec<SignalVector> = Vec::new();
+    let mut backfilled_snapshots: Vec<DocumentSnapshot> = Vec::new();
+    for commit in &older {
+        let contents: Vec<String> = files.iter().map(|f| show(&top, &commit.hash, f)).collect();
+        let contents = [
+            contents[0].as_str(),
+            contents[1].as_str(),
+            contents[2].as_str(),
+        ];
+        let timestamp = state::epoch_to_iso(commit.time);
+        backfilled_snapshots.push(collect::snapshot(&timestamp, contents));
+        let (signals, hashes) = collect::measure(
+            contents[0],
+            contents[1],
+            contents[2],
+            &backfilled_snapshots,
+            &config,
+            &evidence,
+        );
+        let previous = vectors.last().map(|sv| &sv.document_hashes);
+        let mut tags = collect::front_matter_tags(previous, &hashes, contents);
+        tags.sort();
+        tags.dedup();
+
+        vectors.push(SignalVector {
+            timestamp,
+            trigger: "backfill".to_string(),
+            signals,
+            document_hashes: hashes,
+            tags,
+        });
+    }
+
+    let added = vectors.len();
+    vectors.append(&mut history);
+    let mut history = vectors;
+    let trimmed = history.len().saturating_sub(config.max_history);
+    history.drain(..trimmed);
+    state::save_signals(&history)?;
+
+    backfilled_snapshots.append(&mut snapshots);
+    let mut snapshots = backfilled_snapshots;
+    trim_snapshots(&mut snapshots, &history);
+    state::save_snapshots(&snapshots)?;
+
+    let resets = epochs::load_resets()?;
+    let mut analysis = analyze::run(&history, &resets, &config);
+    alerts::apply(&mut analysis, &history, &config)?;
+    state::save_analysis(&analysis)?;
+
+    println!(
+        "{} Backfilled from {} ({} commit(s) touching the documents)",
+        "✓".green(),
+        top.display(),
+        commits.len()
+    );
+    println!("  Added:     {added}");
+    if !overlapping.is_empty() {
+        println!(
+            "  Skipped:   {} at or after the first collection",
+            overlapping.len()
+        );
+    }
+    if no_room > 0 {
+        println!(
+            "  Skipped:   {no_room} oldest commit(s), no room under max_history ({}); raise it in config.json to keep them",
+            config.max_history
+        );
+    }
+    if trimmed > 0 {
+        println!(
+            "  Trimmed:   {trimmed} oldest vector(s) over max_history ({})",
+            config.max_history
+        );
+    }
+    println!("  History:   {} data points", history.len());
+    Ok(())
+}
+
+/// Path of the documents relative to the repository root: the docs
+/// directory when it lies inside the repository, otherwise the root itself.
+fn docs_prefix(top: &Path, docs: &Path) -> String {
+    let docs = docs.canonicalize().unwrap_or_else(|_| docs.to_path_buf());
+    let top = top.canonicalize().unwrap_or_else(|_| top.to_path_buf());
+    match docs.strip_prefix(&top) {
+        Ok(rel) if !rel.as_os_str().is_empty() => format!("{}/", rel.display()),
+        _ => String::new(),
+    }
+}
+
+/// Parse `git log --format="%H %ct"` output, oldest commit first. Log order
+/// isn't chronological across merges, rebases or skewed clocks, so commits
+/// are sorted by time (keeping log order on ties).
+fn parse_log(output: &str) -> Vec<Commit> {
+    let mut commits: Vec<Commit> = output
+        .lines()
+        .filter_map(|line| {
+            let (hash, time) = line.trim().split_once(' ')?;
+            Some(Commit {
+                hash: hash.to_string(),
+                time: time.parse().ok()?,
+            })
+        })
+        .collect();
+    commits.sort_by_key(|c| c.time);
+    commits
+}
+
+/// Drop snapshots older than the first vector kept in `history`, so both
+/// files cover the same span.
+fn trim_snapshots(snapshots: &mut Vec<DocumentSnapshot>, history: &[SignalVector]) {
+    let Some(cutoff) = history
+        .first()
+        .and_then(|sv| state::parse_iso_epoch(&sv.timestamp))
+    else {
+        return;
+    };
+    snapshots.retain(|s| state::parse_iso_epoch(&s.timestamp).is_none_or(|t| t >= cutoff));
+}
+
+/// A file's content at a commit, or empty when it didn't exist yet.
+fn show(repo: &Path, hash: &str, file: &str) -> String {
+    git(repo, &["show", &format!("{hash}:{file}")]).unwrap_or_default()
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_log_lines() {
+        let log = "def456 1772445600\nabc123 1772359200\n\nbroken\n";
+        assert_eq!(
+            parse_log(log),
+            vec![
+                Commit {
+                    hash: "abc123".to_string(),
+                    time: 1772359200
+                },
+                Commit {
+                    hash: "def456".to_string(),
+                    time: 1772445600
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn commits_with_equal_times_keep_log_order() {
+        let commits = parse_log("b 20\na 10\nc 20\n");
+        let hashes: Vec<&str> = commits.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(hashes, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn snapshots_are_trimmed_to_the_history_span() {
+        let at = |day: u32| format!("2026-03-{day:02}T10:00:00Z");
+        let mut snapshots: Vec<DocumentSnapshot> = (1..=4)
+            .map(|day| DocumentSnapshot {
+                timestamp: at(day),
+                documents: Default::default(),
+            })
+            .collect();
+        let history = vec![SignalVector {
+            timestamp: at(3),
+            trigger: "backfill".to_string(),
+            signals: Default::default(),
+            document_hashes: Default::default(),
+            tags: Vec::new(),
+        }];
+        trim_snapshots(&mut snapshots, &history);
+        let kept: Vec<&str> = snapshots.iter().map(|s| s.timestamp.as_str()).collect();
+        assert_eq!(kept, vec![at(3), at(4)]);
+    }
+
+    #[test]
+    fn docs_prefix_is_relative_to_repo() {
+        let top = Path::new("/nonexistent/agent");
+        assert_eq!(
+            docs_prefix(top, Path::new("/nonexistent/agent/notes")),
+            "notes/"
+        );
+        assert_eq!(docs_prefix(top, Path::new("/nonexistent/agent")), "");
+        assert_eq!(docs_prefix(top, Path::new("/elsewhere")), "");
+    }
+}
@@ -0,0 +1,378 @@
+use std::io::{BufRead, IsTerminal, Write};
+
+use owo_colors::OwoColorize;
+
+use crate::state::{self, AlertLevel, Config, Polarity, SignalVector, ThresholdPair, ZoneBounds};
+use crate::{alerts, analyze, epochs, stats};
+
+/// Minimum window deltas per signal before a proposal is made.
+const MIN_DELTAS: usize = 5;
+
+/// Percentiles used to derive proposals.
+pub struct Percentiles {
+    pub decline: f64,
+    pub improve: f64,
+    pub zone_red: f64,
+    pub zone_yellow: f64,
+}
+
+/// Proposed thresholds and zone cutoffs for one signal. Signals without
+/// zones in the config get none proposed, since any zone would pull them
+/// into composite health and zone alerts.
+struct Proposal {
+    name: &'static str,
+    deltas: usize,
+    thresholds: ThresholdPair,
+    zone: Option<ZoneBounds>,
+}
+
+pub fn run(pct: &Percentiles, write: bool) -> Result<(), String> {
+    let config = state::load_config()?;
+    let history = state::load_signals()?;
+
+    println!();
+    println!("  {} — threshold calibration", "vigil-echo".bold());
+    println!();
+    println!(
+        "  {} data points | window: {} | decline p{:.0} / improve p{:.0} | zones p{:.0} / p{:.0}",
+        history.len(),
+        config.window_size,
+        pct.decline,
+        pct.improve,
+        pct.zone_red,
+        pct.zone_yellow
+    );
+
+    let proposals: Vec<Proposal> = analyze::SIGNAL_NAMES
+        .iter()
+        .filter_map(|&name| propose(name, &history, &config, pct))
+        .collect();
+
+    if proposals.is_empty() {
+        println!();
+        println!(
+            "    Not enough history to calibrate (need {} window deltas per signal).",
+            MIN_DELTAS
+        );
+        println!();
+        return Ok(());
+    }
+
+    // Thresholds
+    println!();
+    println!("  {}", "Thresholds".bold());
+    for p in &proposals {
+        let current = config.thresholds.get(p.name);
+        println!(
+            "    {:<24} decline {:>7.3}  improve {:>7.3}   (current {} / {}, {} deltas)",
+            p.name,
+            p.thresholds.decline,
+            p.thresholds.improve,
+            current
+                .map(|t| format!("{:.3}", t.decline))
+                .unwrap_or("—".to_string()),
+            current
+                .map(|t| format!("{:.3}", t.improve))
+                .unwrap_or("—".to_string()),
+            p.deltas,
+        );
+    }
+
+    // Health zones
+    println!();
+    println!("  {}", "Health zones".bold());
+    for p in &proposals {
+        match (&p.zone, config.zones.get(p.name)) {
+            (Some(zone), Some(current)) => println!(
+                "    {:<24} {}   (current {})",
+                p.name,
+                zone.describe(),
+                current.describe()
+            ),
+            _ => println!(
+                "    {:<24} {}",
+                p.name,
+                "— (no zones configured; not proposed)".dimmed()
+            ),
+        }
+    }
+
+    // Replay history under both configs
+    let mut proposed = config.clone();
+    for p in &proposals {
+        proposed
+            .thresholds
+            .insert(p.name.to_string(), p.thresholds.clone());
+        if let Some(zone) = &p.zone {
+            proposed.zones.insert(p.name.to_string(), zone.clone());
+        }
+    }
+    let resets = epochs::load_resets()?;
+    let before = replay(&history, &resets, &config);
+    let after = replay(&history, &resets, &proposed);
+    println!();
+    println!("  {}", "Replay".bold());
+    println!(
+        "    current   WATCH {:>3}  CONCERN {:>3}  ALERT {:>3}",
+        before.watch, before.concern, before.alert
+    );
+    println!(
+        "    proposed  WATCH {:>3}  CONCERN {:>3}  ALERT {:>3}",
+        after.watch, after.concern, after.alert
+    );
+    println!();
+
+    let confirmed = write || (std::io::stdin().is_terminal() && confirm()?);
+    if confirmed {
+        state::save_config(&proposed)?;
+        println!(
+            "{} Wrote proposed thresholds and zones to config.json",
+            "✓".green()
+        );
+    } else {
+        println!("  Re-run with --write to save the proposal.");
+    }
+    Ok(())
+}
+
+fn confirm() -> Result<bool, String> {
+    print!("  Write proposed thresholds and zones to config.json? [y/N] ");
+    std::io::stdout()
+        .flush()
+        .map_err(|e| format!("Failed to flush stdout: {e}"))?;
+    let mut answer = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .map_err(|e| format!("Failed to read answer: {e}"))?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+fn propose(
+    name: &'static str,
+    history: &[SignalVector],
+    config: &Config,
+    pct: &Percentiles,
+) -> Option<Proposal> {
+    let deltas = window_deltas(history, name, config);
+    if deltas.len() < MIN_DELTAS {
+        return None;
+    }
+    let decline = stats::percentile(&deltas, pct.decline)?.min(0.0);
+    let improve = stats::percentile(&deltas, pct.improve)?.max(0.0);
+    // A one-sided distribution still needs a band on both sides
+    let (decline, improve) = match (decline == 0.0, improve == 0.0) {
+        (true, true) => return None,
+        (true, false) => (-improve, improve),
+        (false, true) => (decline, -decline),
+        (false, false) => (decline, improve),
+    };
+
+    Some(Proposal {
+        name,
+        deltas: deltas.len(),
+        thresholds: ThresholdPair { decline, improve },
+        zone: config
+            .zones
+            .contains_key(name)
+            .then(|| propose_zone(name, history, config, pct)),
+    })
+}
+
+/// Zone cutoffs on the unhealthy side(s) of the value distribution.
+fn propose_zone(
+    name: &str,
+    history: &[SignalVector],
+    config: &Config,
+    pct: &Percentiles,
+) -> ZoneBounds {
+    let series = stats::signal_series(history, name);
+    let low = |p: f64| stats::percentile(&series, p);
+    let high = |p: f64| stats::percentile(&series, 100.0 - p);
+    match config.polarity_of(name) {
+        Polarity::HigherIsBetter => ZoneBounds {
+            concern_below: low(pct.zone_red),
+            watch_below: low(pct.zone_yellow),
+            ..ZoneBounds::default()
+        },
+        Polarity::LowerIsBetter => ZoneBounds {
+            watch_above: high(pct.zone_yellow),
+            concern_above: high(pct.zone_red),
+            ..ZoneBounds::default()
+        },
+        // Never inside the target range, which is healthy by definition
+        Polarity::TargetRange {
+            low: min,
+            high: max,
+        } => {
+            let below = |p: f64| low(p).map(|v| v.min(min));
+            let above = |p: f64| high(p).map(|v| v.max(max));
+            ZoneBounds {
+                concern_below: below(pct.zone_red),
+                watch_below: below(pct.zone_yellow),
+                watch_above: above(pct.zone_yellow),
+                concern_above: above(pct.zone_red),
+            }
+        }
+    }
+}
+
+/// Health-oriented deltas the mean-delta method would have seen at each past
+/// collection: mean of the last 3 points vs mean of the rest of the window.
+fn window_deltas(history: &[SignalVector], name: &str, config: &Config) -> Vec<f64> {
+    let series = stats::signal_series(history, name);
+    let polarity = config.polarity_of(name);
+    let mut deltas = Vec::new();
+    for end in 4..=series.len() {
+        let start = end.saturating_sub(config.window_size.max(4));
+        let window = &series[start..end];
+        let split = window.len() - 3;
+        if let (Some(b), Some(r)) = (stats::mean(&window[..split]), stats::mean(&window[split..])) {
+            deltas.push(polarity.improvement(b, r));
+        }
+    }
+    deltas
+}
+
+#[derive(Default)]
+struct LevelCounts {
+    watch: usize,
+    concern: usize,
+    alert: usize,
+}
+
+/// Alert levels the alert machine would have held after each past collection.
+fn replay(history: &[SignalVector], resets: &[String], config: &Config) -> LevelCounts {
+    let mut counts = LevelCounts::default();
+    for level in alerts::replay(history, resets, config) {
+        match level {
+            AlertLevel::Healthy => {}
+            AlertLevel::Watch => counts.watch += 1,
+            AlertLevel::Concern => counts.concern += 1,
+            AlertLevel::Alert => counts.alert += 1,
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(vd: f64) -> SignalVector {
+        SignalVector {
+            timestamp: "2026-02-27T10:00:00Z".to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(vd),
+                question_generation: Some(5.0),
+                thought_lifecycle: Some(0.5),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn default_pct() -> Percentiles {
+        Percentiles {
+            decline: 10.0,
+            improve: 90.0,
+            zone_red: 10.0,
+            zone_yellow: 25.0,
+        }
+    }
+
+    #[test]
+    fn window_deltas_match_mean_delta() {
+        let history: Vec<SignalVector> = [0.5, 0.5, 0.5, 0.5, 0.2]
+            .iter()
+            .map(|&v| make_vector(v))
+            .collect();
+        let deltas = window_deltas(&history, "vocabulary_diversity", &Config::default());
+        assert_eq!(deltas.len(), 2);
+        assert!(deltas[0].abs() < 1e-9);
+        assert!((deltas[1] + 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn proposes_bands_on_both_sides() {
+        let history: Vec<SignalVector> = (0..20)
+            .map(|i| make_vector(0.5 + (i % 3) as f64 * 0.05))
+            .collect();
+        let p = propose(
+            "vocabulary_diversity",
+            &history,
+            &Config::default(),
+            &default_pct(),
+        )
+        .unwrap();
+        assert!(p.thresholds.decline < 0.0);
+        assert!(p.thresholds.improve > 0.0);
+        let zone = p.zone.unwrap();
+        assert!(zone.concern_below.unwrap() <= zone.watch_below.unwrap());
+        assert!(zone.concern_above.is_none());
+    }
+
+    #[test]
+    fn lower_is_better_zones_sit_above() {
+        let history: Vec<SignalVector> = (0..20)
+            .map(|i| make_vector(0.5 + (i % 3) as f64 * 0.05))
+            .collect();
+        let mut config = Config::default();
+        config
+            .polarity
+            .insert("vocabulary_diversity".to_string(), Polarity::LowerIsBetter);
+        let p = propose("vocabulary_diversity", &history, &config, &default_pct()).unwrap();
+        let zone = p.zone.unwrap();
+        assert!(zone.concern_below.is_none());
+        assert!(zone.watch_above.unwrap() <= zone.concern_above.unwrap());
+    }
+
+    #[test]
+    fn signals_without_zones_get_none_proposed() {
+        let history: Vec<SignalVector> = (0..20)
+            .map(|i| make_vector(0.5 + (i % 3) as f64 * 0.05))
+            .collect();
+        let mut config = Config::default();
+        config.zones.remove("vocabulary_diversity");
+        let p = propose("vocabulary_diversity", &history, &config, &default_pct()).unwrap();
+        assert!(p.thresholds.decline < 0.0);
+        assert!(p.zone.is_none());
+    }
+
+    #[test]
+    fn target_range_zones_stay_outside_the_range() {
+        let history: Vec<SignalVector> = (0..20)
+            .map(|i| make_vector(0.4 + i as f64 * 0.01))
+            .collect();
+        let mut config = Config::default();
+        config.polarity.insert(
+            "vocabulary_diversity".to_string(),
+            Polarity::TargetRange {
+                low: 0.43,
+                high: 0.55,
+            },
+        );
+        let zone = propose("vocabulary_diversity", &history, &config, &default_pct())
+            .unwrap()
+            .zone
+            .unwrap();
+        assert!(zone.watch_below.unwrap() <= 0.43);
+        assert!(zone.concern_below.unwrap() <= zone.watch_below.unwrap());
+        assert!(zone.watch_above.unwrap() >= 0.55);
+        assert!(zone.concern_above.unwrap() >= zone.watch_above.unwrap());
+    }
+
+    #[test]
+    fn no_proposal_for_flat_or_short_history() {
+        let flat: Vec<SignalVector> = (0..20).map(|_| make_vector(0.5)).collect();
+        let config = Config::default();
+        assert!(propose("vocabulary_diversity", &flat, &config, &default_pct()).is_none());
+        let short: Vec<SignalVector> = (0..5).map(|i| make_vector(i as f64)).collect();
+        assert!(propose("vocabulary_diversity", &short, &config, &default_pct()).is_none());
+        assert!(propose("evidence_grounding", &flat, &config, &default_pct()).is_none());
+    }
+}
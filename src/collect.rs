@@ -2,46 +2,63 @@ use std::collections::HashMap;
 
 use owo_colors::OwoColorize;
 
-use crate::{analyze, parser, paths, signals, state};
+use crate::{alerts, analyze, epochs, parser, paths, signals, state};
 
-pub fn run(trigger: &str) -> Result<(), String> {
+/// Keys in `document_hashes`, in the order REFLECTIONS, THOUGHTS, CURIOSITY.
+const DOCUMENT_KEYS: [&str; 3] = ["reflections", "thoughts", "curiosity"];
+
+/// File names of the identity documents, in the same order.
+pub const DOCUMENT_FILES: [&str; 3] = ["REFLECTIONS.md", "THOUGHTS.md", "CURIOSITY.md"];
+
+pub fn run(trigger: &str, cli_tags: &[String]) -> Result<(), String> {
     let reflections_content = parser::read_or_empty(&paths::reflections_file()?);
     let thoughts_content = parser::read_or_empty(&paths::thoughts_file()?);
     let curiosity_content = parser::read_or_empty(&paths::curiosity_file()?);
 
-    // Extract signals
-    let sigs = state::Signals {
-        vocabulary_diversity: signals::vocabulary_diversity(&reflections_content),
-        question_generation: signals::question_generation(&curiosity_content),
-        thought_lifecycle: signals::thought_lifecycle(&thoughts_content),
-        evidence_grounding: signals::evidence_grounding(&reflections_content),
-    };
+    // Load config and existing history
+    let config = state::load_config()?;
+    let mut history = state::load_signals()?;
+    let mut snapshots = state::load_snapshots()?;
 
-    // Document hashes for change detection
-    let mut hashes = HashMap::new();
-    hashes.insert(
-        "reflections".to_string(),
-        parser::hash_content(&reflections_content),
-    );
-    hashes.insert(
-        "thoughts".to_string(),
-        parser::hash_content(&thoughts_content),
-    );
-    hashes.insert(
-        "curiosity".to_string(),
-        parser::hash_content(&curiosity_content),
+    // Entry titles per section, for new/graduated entries in reports and for
+    // signals that follow entries across collections
+    let vector_timestamp = state::now_iso();
+    snapshots.push(snapshot(
+        &vector_timestamp,
+        [&reflections_content, &thoughts_content, &curiosity_content],
+    ));
+
+    let evidence = signals::EvidenceMatcher::new(&config.evidence, Some(&paths::docs_dir()?))?;
+    let (sigs, hashes) = measure(
+        &reflections_content,
+        &thoughts_content,
+        &curiosity_content,
+        &snapshots,
+        &config,
+        &evidence,
     );
 
+    // Tags: from the command line, plus front matter of documents written this session
+    let previous = history.last().map(|sv| &sv.document_hashes);
+    let mut tags: Vec<String> = cli_tags.iter().map(|t| t.trim().to_string()).collect();
+    tags.extend(front_matter_tags(
+        previous,
+        &hashes,
+        [&reflections_content, &thoughts_content, &curiosity_content],
+    ));
+    tags.retain(|t| !t.is_empty());
+    tags.sort();
+    tags.dedup();
+
     let vector = state::SignalVector {
-        timestamp: state::now_iso(),
+        timestamp: vector_timestamp,
         trigger: trigger.to_string(),
         signals: sigs.clone(),
         document_hashes: hashes,
+        tags: tags.clone(),
     };
 
-    // Load existing history, append, trim to max
-    let config = state::load_config()?;
-    let mut history = state::load_signals()?;
+    // Append, trim to max
     history.push(vector);
     if history.len() > config.max_history {
         let excess = history.len() - config.max_history;
@@ -49,8 +66,16 @@ pub fn run(trigger: &str) -> Result<(), String> {
     }
     state::save_signals(&history)?;
 
+    if snapshots.len() > config.max_history {
+        let excess = snapshots.len() - config.max_history;
+        snapshots.drain(..excess);
+    }
+    state::save_snapshots(&snapshots)?;
+
     // Run analysis
-    let analysis = analyze::run(&history, &config);
+    let resets = epochs::load_resets()?;
+    let mut analysis = analyze::run(&history, &resets, &config);
+    alerts::apply(&mut analysis, &history, &config)?;
     state::save_analysis(&analysis)?;
 
     // Print summary
@@ -59,6 +84,17 @@ pub fn run(trigger: &str) -> Result<(), String> {
     print_signal("  question_generation", sigs.question_generation);
     print_signal("  thought_lifecycle", sigs.thought_lifecycle);
     print_signal("  evidence_grounding", sigs.evidence_grounding);
+    print_signal("  hedging_balance", sigs.hedging_balance);
+    print_signal("  question_depth", sigs.question_depth);
+    print_signal("  questions_opened", sigs.questions_opened);
+    print_signal("  questions_resolved", sigs.questions_resolved);
+    print_signal("  questions_abandoned", sigs.questions_abandoned);
+    print_signal("  open_question_age", sigs.open_question_age);
+    print_signal("  thought_resolution_time", sigs.thought_resolution_time);
+    print_signal("  stale_thoughts", sigs.stale_thoughts);
+    if !tags.is_empty() {
+        println!("  Tags: {}", tags.join(", "));
+    }
     println!(
         "  History: {} data points ({} max)",
         history.len(),
@@ -68,6 +104,67 @@ pub fn run(trigger: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Signals and change-detection hashes for one reading of the documents.
+/// `snapshots` ends with the snapshot of these same documents, preceded by
+/// earlier collections, for signals that follow entries over time.
+pub fn measure(
+    reflections: &str,
+    thoughts: &str,
+    curiosity: &str,
+    snapshots: &[state::DocumentSnapshot],
+    config: &state::Config,
+    evidence: &signals::EvidenceMatcher,
+) -> (state::Signals, HashMap<String, String>) {
+    let sigs = state::Signals {
+        vocabulary_diversity: signals::vocabulary_diversity(reflections),
+        question_generation: signals::question_generation(curiosity),
+        thought_lifecycle: signals::thought_lifecycle(thoughts),
+        evidence_grounding: signals::evidence_grounding(reflections, evidence),
+        hedging_balance: signals::hedging_balance(reflections, &config.hedging),
+        question_depth: signals::question_depth(curiosity),
+        questions_opened: signals::questions_opened(curiosity, snapshots.iter().rev().nth(1)),
+        questions_resolved: signals::questions_resolved(snapshots),
+        questions_abandoned: signals::questions_abandoned(snapshots),
+        open_question_age: signals::open_question_age(snapshots),
+        thought_resolution_time: signals::thought_resolution_time(snapshots),
+        stale_thoughts: signals::stale_thoughts(snapshots, config.stale_thought_sessions),
+    };
+    let hashes = DOCUMENT_KEYS
+        .iter()
+        .zip([reflections, thoughts, curiosity])
+        .map(|(key, content)| (key.to_string(), parser::hash_content(content)))
+        .collect();
+    (sigs, hashes)
+}
+
+/// Front matter tags of the documents whose hash changed since `previous`.
+/// `contents` are REFLECTIONS.md, THOUGHTS.md and CURIOSITY.md, in that order.
+pub fn front_matter_tags(
+    previous: Option<&HashMap<String, String>>,
+    hashes: &HashMap<String, String>,
+    contents: [&str; 3],
+) -> Vec<String> {
+    DOCUMENT_KEYS
+        .iter()
+        .zip(contents)
+        .filter(|(key, _)| previous.is_none_or(|p| p.get(**key) != hashes.get(**key)))
+        .flat_map(|(_, content)| parser::front_matter_tags(content))
+        .collect()
+}
+
+/// Entry titles per section, for new/graduated entries in reports.
+pub fn snapshot(timestamp: &str, contents: [&str; 3]) -> state::DocumentSnapshot {
+    state::DocumentSnapshot {
+        timestamp: timestamp.to_string(),
+        documents: DOCUMENT_FILES
+            .iter()
+            .zip(contents)
+            .filter(|(_, content)| !content.is_empty())
+            .map(|(doc, content)| (doc.to_string(), parser::h3_titles_by_section(content)))
+            .collect(),
+    }
+}
+
 fn print_signal(label: &str, value: Option<f64>) {
     match value {
         Some(v) => println!("{label}: {v:.2}"),
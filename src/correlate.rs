@@ -0,0 +1,272 @@
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::analyze::{self, SIGNAL_NAMES};
+use crate::state::{self, SignalVector};
+use crate::stats;
+
+/// Fewest paired observations before a correlation is reported.
+const MIN_PAIRS: usize = 5;
+
+/// Lagged correlations at or above this strength are listed as leading indicators.
+const LEAD_STRENGTH: f64 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Method {
+    Pearson,
+    Spearman,
+}
+
+/// Correlation of signal `a` at t with signal `b` at t + `lag`.
+#[derive(Debug)]
+struct Pair {
+    a: &'static str,
+    b: &'static str,
+    r: Option<f64>,
+    n: usize,
+}
+
+pub fn run(method: Method, lag: usize, redundant: f64, json_output: bool) -> Result<(), String> {
+    let history = state::load_signals()?;
+    let lag = lag.max(1);
+    let same = matrix(&history, method, 0);
+    let lagged = matrix(&history, method, lag);
+    let redundant_pairs: Vec<&Pair> = same
+        .iter()
+        .filter(|p| p.a < p.b && p.r.is_some_and(|r| r.abs() >= redundant))
+        .collect();
+
+    if json_output {
+        let to_json = |pairs: &[Pair]| {
+            let mut rows = serde_json::Map::new();
+            for &a in SIGNAL_NAMES {
+                let row: serde_json::Map<String, serde_json::Value> = pairs
+                    .iter()
+                    .filter(|p| p.a == a)
+                    .map(|p| (p.b.to_string(), serde_json::json!(p.r)))
+                    .collect();
+                rows.insert(a.to_string(), serde_json::Value::Object(row));
+            }
+            serde_json::Value::Object(rows)
+        };
+        let output = serde_json::json!({
+            "method": method,
+            "collections": history.len(),
+            "matrix": to_json(&same),
+            "lag": lag,
+            "lagged": to_json(&lagged),
+            "redundant": redundant_pairs
+                .iter()
+                .map(|p| serde_json::json!({ "a": p.a, "b": p.b, "r": p.r, "n": p.n }))
+                .collect::<Vec<_>>(),
+        });
+        let json_str = serde_json::to_string_pretty(&output)
+            .map_err(|e| format!("JSON serialization failed: {e}"))?;
+        println!("{json_str}");
+        return Ok(());
+    }
+
+    let symbol = match method {
+        Method::Pearson => "r",
+        Method::Spearman => "ρ",
+    };
+    println!();
+    println!("  {} — signal correlation", "vigil-echo".bold());
+    println!();
+    println!(
+        "  {:?} ({symbol}) over {} collections",
+        method,
+        history.len()
+    );
+
+    println!();
+    println!("  {}", "Same session".bold());
+    print_matrix(&same);
+
+    println!();
+    println!(
+        "  {}",
+        format!("Lag {} (row at t, column at t+{})", lag, lag).bold()
+    );
+    print_matrix(&lagged);
+
+    let mut leads: Vec<&Pair> = lagged
+        .iter()
+        .filter(|p| p.a != p.b && p.r.is_some_and(|r| r.abs() >= LEAD_STRENGTH))
+        .collect();
+    leads.sort_by(|x, y| {
+        let strength = |p: &Pair| p.r.unwrap_or(0.0).abs();
+        strength(y).total_cmp(&strength(x))
+    });
+    if !leads.is_empty() {
+        println!();
+        println!("  {}", "Leading indicators".bold());
+        for p in leads {
+            println!(
+                "    {} at t → {} at t+{}  {symbol} = {:+.2}  (n = {})",
+                p.a,
+                p.b,
+                lag,
+                p.r.unwrap_or(0.0),
+                p.n
+            );
+        }
+    }
+
+    println!();
+    println!("  {}", "Redundant pairs".bold());
+    if redundant_pairs.is_empty() {
+        println!("    None (|{symbol}| ≥ {redundant:.2}).");
+    }
+    for p in redundant_pairs {
+        println!(
+            "    {} {} ~ {}  {symbol} = {:+.2}  (n = {}) — they move together; consider dropping or down-weighting one",
+            "!".yellow().bold(),
+            p.a,
+            p.b,
+            p.r.unwrap_or(0.0),
+            p.n
+        );
+    }
+    println!();
+    Ok(())
+}
+
+fn print_matrix(pairs: &[Pair]) {
+    let header: String = SIGNAL_NAMES
+        .iter()
+        .map(|name| format!("{:>10}", short_name(name)))
+        .collect();
+    println!("    {:<24}{}", "", header.dimmed());
+    for &a in SIGNAL_NAMES {
+        let cells: String = pairs
+            .iter()
+            .filter(|p| p.a == a)
+            .map(|p| match p.r {
+                Some(r) if r.abs() >= 0.7 => format!("{}", format!("{:>10.2}", r).bold()),
+                Some(r) => format!("{:>10.2}", r),
+                None => format!("{}", format!("{:>10}", "—").dimmed()),
+            })
+            .collect();
+        println!("    {:<24}{}", a, cells);
+    }
+}
+
+/// Correlation for every ordered pair of signals.
+fn matrix(history: &[SignalVector], method: Method, lag: usize) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+    for &a in SIGNAL_NAMES {
+        for &b in SIGNAL_NAMES {
+            let (x, y) = paired(history, a, b, lag);
+            let r = if x.len() < MIN_PAIRS {
+                None
+            } else {
+                match method {
+                    Method::Pearson => stats::pearson(&x, &y),
+                    Method::Spearman => stats::spearman(&x, &y),
+                }
+            };
+            pairs.push(Pair {
+                a,
+                b,
+                r,
+                n: x.len(),
+            });
+        }
+    }
+    pairs
+}
+
+/// Values of `a` at t and `b` at t + `lag`, for every t where both exist.
+fn paired(history: &[SignalVector], a: &str, b: &str, lag: usize) -> (Vec<f64>, Vec<f64>) {
+    history
+        .iter()
+        .zip(history.iter().skip(lag))
+        .filter_map(|(now, later)| {
+            Some((analyze::get_signal(now, a)?, analyze::get_signal(later, b)?))
+        })
+        .unzip()
+}
+
+fn short_name(name: &str) -> &str {
+    match name {
+        "vocabulary_diversity" => "vocab",
+        "question_generation" => "question",
+        "thought_lifecycle" => "thought",
+        "evidence_grounding" => "evidence",
+        "hedging_balance" => "hedging",
+        "question_depth" => "depth",
+        "questions_opened" => "opened",
+        "questions_resolved" => "resolved",
+        "questions_abandoned" => "abandoned",
+        "open_question_age" => "q-age",
+        "thought_resolution_time" => "t-resolve",
+        "stale_thoughts" => "stale",
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(vd: f64, qg: f64, eg: Option<f64>) -> SignalVector {
+        SignalVector {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(vd),
+                question_generation: Some(qg),
+                evidence_grounding: eg,
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn pairs_skip_missing_values_and_shift_by_lag() {
+        let history = vec![
+            make_vector(0.1, 1.0, Some(0.5)),
+            make_vector(0.2, 2.0, None),
+            make_vector(0.3, 3.0, Some(0.7)),
+        ];
+        let (x, y) = paired(&history, "evidence_grounding", "question_generation", 1);
+        assert_eq!(x, vec![0.5]);
+        assert_eq!(y, vec![2.0]);
+        let (x, _) = paired(&history, "vocabulary_diversity", "evidence_grounding", 0);
+        assert_eq!(x, vec![0.1, 0.3]);
+    }
+
+    #[test]
+    fn detects_lagged_relationship() {
+        // Questions follow vocabulary diversity one collection later
+        let vd = [0.2, 0.8, 0.4, 0.9, 0.1, 0.6, 0.3, 0.7];
+        let history: Vec<SignalVector> = (0..vd.len())
+            .map(|i| {
+                let qg = if i == 0 { 5.0 } else { vd[i - 1] * 10.0 };
+                make_vector(vd[i], qg, None)
+            })
+            .collect();
+        let lagged = matrix(&history, Method::Pearson, 1);
+        let p = lagged
+            .iter()
+            .find(|p| p.a == "vocabulary_diversity" && p.b == "question_generation")
+            .unwrap();
+        assert!((p.r.unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(p.n, 7);
+    }
+
+    #[test]
+    fn short_series_are_not_reported() {
+        let history: Vec<SignalVector> = (0..4)
+            .map(|i| make_vector(i as f64, i as f64, None))
+            .collect();
+        let same = matrix(&history, Method::Spearman, 0);
+        assert!(same.iter().all(|p| p.r.is_none()));
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::Range;
+
+use owo_colors::OwoColorize;
+
+use crate::state::{self, Epoch, SignalVector};
+use crate::{analyze, annotations, stats};
+
+/// `vigil-echo epoch start`: begin a new named epoch now.
+pub fn start(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Epoch name is empty".to_string());
+    }
+    let mut epochs = state::load_epochs()?;
+    if epochs.iter().any(|e| e.name == name) {
+        return Err(format!("Epoch '{name}' already exists"));
+    }
+    let started = state::now_iso();
+    epochs.push(Epoch {
+        name: name.to_string(),
+        started: started.clone(),
+    });
+    state::save_epochs(&epochs)?;
+
+    println!("{} Started epoch \"{name}\" at {started}", "✓".green());
+    println!("  Trend baselines and status statistics restart from the next collection.");
+    Ok(())
+}
+
+/// `vigil-echo epoch list`: every epoch with per-signal mean and sd, for comparison.
+pub fn list() -> Result<(), String> {
+    let history = state::load_signals()?;
+    let epochs = state::load_epochs()?;
+
+    println!();
+    println!("  {} — epochs", "vigil-echo".bold());
+    println!();
+    if epochs.is_empty() {
+        println!("    No epochs yet. Start one with `vigil-echo epoch start \"name\"`.");
+        println!();
+        return Ok(());
+    }
+
+    let spans = spans(&history, &epochs);
+    let current = spans.len() - 1;
+    for (i, (name, started, range)) in spans.iter().enumerate() {
+        let marker = if i == current {
+            format!(" {}", "(current)".green())
+        } else {
+            String::new()
+        };
+        let since = started
+            .as_deref()
+            .map(|s| format!(" since {s}"))
+            .unwrap_or_default();
+        println!(
+            "  {}{}{} — {} collections",
+            name.bold(),
+            marker,
+            since.dimmed(),
+            range.len()
+        );
+        let slice = &history[range.clone()];
+        for &signal in analyze::SIGNAL_NAMES {
+            let series = stats::signal_series(slice, signal);
+            match (stats::mean(&series), stats::std_dev(&series)) {
+                (Some(m), Some(sd)) => {
+                    println!("    {:<24} mean {:.2}  sd {:.2}", signal, m, sd)
+                }
+                (Some(m), None) => println!("    {:<24} mean {:.2}", signal, m),
+                _ => println!("    {:<24} {}", signal, "—".dimmed()),
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
+
+/// Timestamps trend baselines restart from: reset annotations and epoch starts.
+pub fn load_resets() -> Result<Vec<String>, String> {
+    let mut resets = annotations::resets(&state::load_annotations()?);
+    resets.extend(state::load_epochs()?.into_iter().map(|e| e.started));
+    Ok(resets)
+}
+
+/// The epoch that started most recently.
+pub fn current(epochs: &[Epoch]) -> Option<&Epoch> {
+    epochs
+        .iter()
+        .max_by_key(|e| state::parse_iso_epoch(&e.started))
+}
+
+/// Index of the first collection in the current epoch (0 without epochs).
+pub fn current_start(history: &[SignalVector], epochs: &[Epoch]) -> usize {
+    spans(history, epochs)
+        .last()
+        .map(|(_, _, range)| range.start)
+        .unwrap_or(0)
+}
+
+/// Collection ranges per epoch, in start order. Collections made before the
+/// first epoch form an unnamed leading span.
+fn spans(
+    history: &[SignalVector],
+    epochs: &[Epoch],
+) -> Vec<(String, Option<String>, Range<usize>)> {
+    let mut sorted: Vec<&Epoch> = epochs.iter().collect();
+    sorted.sort_by_key(|e| state::parse_iso_epoch(&e.started));
+    let first_at = |started: &str| {
+        let at = state::parse_iso_epoch(started).unwrap_or(0);
+        history
+            .iter()
+            .position(|sv| state::parse_iso_epoch(&sv.timestamp).is_some_and(|t| t >= at))
+            .unwrap_or(history.len())
+    };
+    let starts: Vec<usize> = sorted.iter().map(|e| first_at(&e.started)).collect();
+
+    let mut spans = Vec::new();
+    if starts.first().is_some_and(|&s| s > 0) {
+        spans.push(("(before epochs)".to_string(), None, 0..starts[0]));
+    }
+    for (i, epoch) in sorted.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(history.len());
+        spans.push((
+            epoch.name.clone(),
+            Some(epoch.started.clone()),
+            starts[i]..end,
+        ));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(day: usize) -> SignalVector {
+        SignalVector {
+            timestamp: format!("2026-03-{:02}T10:00:00Z", day),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(0.5),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn epoch(name: &str, started: &str) -> Epoch {
+        Epoch {
+            name: name.to_string(),
+            started: started.to_string(),
+        }
+    }
+
+    #[test]
+    fn spans_split_history_at_epoch_starts() {
+        let history: Vec<SignalVector> = (1..=6).map(make_vector).collect();
+        let epochs = vec![
+            epoch("v3", "2026-03-05T00:00:00Z"),
+            epoch("v2", "2026-03-02T12:00:00Z"),
+        ];
+        let spans = spans(&history, &epochs);
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].2, 0..2);
+        assert_eq!(spans[1].0, "v2");
+        assert_eq!(spans[1].2, 2..4);
+        assert_eq!(spans[2].2, 4..6);
+        assert_eq!(current_start(&history, &epochs), 4);
+    }
+
+    #[test]
+    fn new_epoch_starts_empty() {
+        let history: Vec<SignalVector> = (1..=3).map(make_vector).collect();
+        let epochs = vec![epoch("v2", "2026-03-10T00:00:00Z")];
+        assert_eq!(current_start(&history, &epochs), 3);
+        assert_eq!(current_start(&history, &[]), 0);
+    }
+}
@@ -0,0 +1,634 @@
+use std::collections::HashMap;
+
+use owo_colors::OwoColorize;
+
+use crate::lineage::{self, Lineage};
+use crate::signals::{self, EvidenceMatcher, QuestionKind, REFLECTION_SECTIONS};
+use crate::state::{DocumentSnapshot, HedgingLexicon};
+use crate::{analyze, parser, paths, state};
+
+/// Most repeated words listed for vocabulary_diversity.
+const TOP_WORDS: usize = 10;
+
+/// Sections of THOUGHTS.md that thought_lifecycle counts.
+const THOUGHT_SECTIONS: [&str; 3] = ["Active", "Graduated", "Dissolved"];
+
+/// `vigil-echo explain <signal>`: show which entries drove the current score.
+pub fn run(signal: &str, json_output: bool) -> Result<(), String> {
+    if !analyze::SIGNAL_NAMES.contains(&signal) {
+        return Err(format!(
+            "Unknown signal '{signal}' (expected one of: {})",
+            analyze::SIGNAL_NAMES.join(", ")
+        ));
+    }
+    let output = match signal {
+        "vocabulary_diversity" => vocabulary(&parser::read_or_empty(&paths::reflections_file()?)),
+        "question_generation" => questions(&parser::read_or_empty(&paths::curiosity_file()?)),
+        "thought_lifecycle" => thoughts(&parser::read_or_empty(&paths::thoughts_file()?)),
+        "question_depth" => depth(&parser::read_or_empty(&paths::curiosity_file()?)),
+        // Against the collection before the latest, matching the recorded value
+        "questions_opened" => opened(
+            &parser::read_or_empty(&paths::curiosity_file()?),
+            state::load_snapshots()?.iter().rev().nth(1),
+        ),
+        "questions_resolved" | "questions_abandoned" | "open_question_age" => {
+            resolution(signal, &state::load_snapshots()?)
+        }
+        "thought_resolution_time" | "stale_thoughts" => thought_ages(
+            signal,
+            &state::load_snapshots()?,
+            state::load_config()?.stale_thought_sessions,
+        ),
+        "hedging_balance" => hedging(
+            &parser::read_or_empty(&paths::reflections_file()?),
+            &state::load_config()?.hedging,
+        ),
+        _ => {
+            let evidence =
+                EvidenceMatcher::new(&state::load_config()?.evidence, Some(&paths::docs_dir()?))?;
+            grounding(
+                &parser::read_or_empty(&paths::reflections_file()?),
+                &evidence,
+            )
+        }
+    };
+
+    if json_output {
+        let json_str = serde_json::to_string_pretty(&output.json)
+            .map_err(|e| format!("JSON serialization failed: {e}"))?;
+        println!("{json_str}");
+        return Ok(());
+    }
+
+    println!();
+    println!("  {} — explain {signal}", "vigil-echo".bold());
+    println!();
+    match output.value {
+        Some(v) => println!("  Score: {v:.2}{}", output.summary),
+        None => println!("  Score: — (document missing or nothing to measure)"),
+    }
+    println!("  {}", format!("Source: {}", output.source).dimmed());
+    println!();
+    for line in &output.lines {
+        println!("    {line}");
+    }
+    println!();
+    Ok(())
+}
+
+/// What `explain` prints for one signal, and the same as JSON.
+struct Explanation {
+    value: Option<f64>,
+    summary: String,
+    source: String,
+    lines: Vec<String>,
+    json: serde_json::Value,
+}
+
+fn grounding(reflections: &str, evidence: &EvidenceMatcher) -> Explanation {
+    let entries = parser::extract_entries(reflections, &REFLECTION_SECTIONS);
+    let verdicts: Vec<(&str, f64, Vec<signals::EvidenceMarker>)> = entries
+        .iter()
+        .map(|(title, body)| {
+            (
+                title.as_str(),
+                evidence.credit(body),
+                evidence.markers(body),
+            )
+        })
+        .collect();
+    let grounded = verdicts
+        .iter()
+        .filter(|(_, credit, _)| *credit > 0.0)
+        .count();
+
+    let width = title_width(verdicts.iter().map(|(t, _, _)| *t));
+    let mut lines: Vec<String> = verdicts
+        .iter()
+        .map(|(title, credit, markers)| {
+            if markers.is_empty() {
+                return format!("{} {:<width$}  {}", "✗".red(), title, "none".dimmed());
+            }
+            let found: Vec<String> = markers
+                .iter()
+                .map(|m| format!("{} \"{}\"", m.category, m.marker))
+                .collect();
+            let mark = if *credit >= 1.0 {
+                "✓".green().to_string()
+            } else {
+                "~".yellow().to_string()
+            };
+            format!("{mark} {title:<width$}  {credit:.2}  {}", found.join(", "))
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push("No ### entries under Observations, Patterns or Lessons.".to_string());
+    }
+
+    // Matches per evidence type across all entries
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for (_, body) in &entries {
+        for (i, (name, n)) in evidence.counts(body).into_iter().enumerate() {
+            match counts.get_mut(i) {
+                Some(total) => total.1 += n,
+                None => counts.push((name, n)),
+            }
+        }
+    }
+    let found: Vec<String> = counts
+        .iter()
+        .filter(|(_, n)| *n > 0)
+        .map(|(name, n)| format!("{name} {n}"))
+        .collect();
+    if !found.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("Evidence by type: {}", found.join(", ")));
+    }
+
+    let value = signals::evidence_grounding(reflections, evidence);
+    Explanation {
+        value,
+        summary: format!(
+            " ({grounded} of {} entries with evidence; mean credit per entry)",
+            entries.len()
+        ),
+        source: "REFLECTIONS.md — Observations, Patterns, Lessons".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "evidence_grounding",
+            "value": value,
+            "entries": verdicts
+                .iter()
+                .map(|(title, credit, markers)| serde_json::json!({
+                    "title": title,
+                    "credit": credit,
+                    "markers": markers
+                        .iter()
+                        .map(|m| serde_json::json!({ "category": m.category, "marker": m.marker }))
+                        .collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+            "counts": counts
+                .iter()
+                .map(|(name, n)| (name.clone(), serde_json::json!(n)))
+                .collect::<serde_json::Map<_, _>>(),
+        }),
+    }
+}
+
+fn questions(curiosity: &str) -> Explanation {
+    let open = signals::open_questions(curiosity);
+    let mut lines: Vec<String> = open.iter().map(|q| format!("? {q}")).collect();
+    if lines.is_empty() {
+        lines.push("No ### headings under an Open Questions section.".to_string());
+    }
+    Explanation {
+        value: signals::question_generation(curiosity),
+        summary: format!(" ({} counted heading(s))", open.len()),
+        source: "CURIOSITY.md — Open Questions (or any section containing \"Open\")".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "question_generation",
+            "value": signals::question_generation(curiosity),
+            "questions": open,
+        }),
+    }
+}
+
+fn depth(curiosity: &str) -> Explanation {
+    let scored: Vec<(String, QuestionKind, usize, bool, f64)> =
+        signals::open_question_entries(curiosity)
+            .into_iter()
+            .map(|(title, body)| {
+                let words = parser::normalize_title(&title).split(' ').count();
+                let explored = parser::tokenize(&body).len() >= signals::QUESTION_MIN_BODY_WORDS;
+                let quality = signals::question_quality(&title, &body);
+                let kind = QuestionKind::classify(&title);
+                (title, kind, words, explored, quality)
+            })
+            .collect();
+
+    let width = title_width(scored.iter().map(|q| q.0.as_str()));
+    let mut lines: Vec<String> = scored
+        .iter()
+        .map(|(title, kind, words, explored, quality)| {
+            format!(
+                "{quality:.2}  {title:<width$}  {:<6} {words:>2} words{}",
+                kind.label(),
+                if *explored { ", explored" } else { "" }
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push("No ### headings under an Open Questions section.".to_string());
+    }
+
+    let value = signals::question_depth(curiosity);
+    let count = |k: QuestionKind| scored.iter().filter(|q| q.1 == k).count();
+    Explanation {
+        value,
+        summary: format!(
+            " ({} deep, {} open, {} yes/no, {} other; mean quality per question)",
+            count(QuestionKind::Deep),
+            count(QuestionKind::Open),
+            count(QuestionKind::Closed),
+            count(QuestionKind::Other)
+        ),
+        source: "CURIOSITY.md — Open Questions (or any section containing \"Open\")".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "question_depth",
+            "value": value,
+            "questions": scored
+                .iter()
+                .map(|(title, kind, words, explored, quality)| serde_json::json!({
+                    "title": title,
+                    "kind": kind.label(),
+                    "words": words,
+                    "explored": explored,
+                    "quality": quality,
+                }))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn opened(curiosity: &str, previous: Option<&DocumentSnapshot>) -> Explanation {
+    let new = signals::new_questions(curiosity, previous);
+    let mut lines: Vec<String> = new.iter().flatten().map(|q| format!("+ {q}")).collect();
+    if lines.is_empty() {
+        lines.push(match (&new, previous) {
+            (None, None) => "No earlier collection to compare against.".to_string(),
+            _ => "No new open questions.".to_string(),
+        });
+    }
+    let value = signals::questions_opened(curiosity, previous);
+    Explanation {
+        value,
+        summary: previous.map_or(String::new(), |p| format!(" (since {})", p.timestamp)),
+        source: "CURIOSITY.md — Open Questions, against the previous collection's snapshot"
+            .to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "questions_opened",
+            "value": value,
+            "since": previous.map(|p| &p.timestamp),
+            "questions": new.unwrap_or_default(),
+        }),
+    }
+}
+
+/// Open questions with their ages, and those that left the open sections at
+/// the latest collection. Read from snapshots, so it explains the recorded
+/// values rather than the documents as they are now.
+fn resolution(signal: &str, snapshots: &[DocumentSnapshot]) -> Explanation {
+    let latest = snapshots.last().map(|s| s.timestamp.as_str()).unwrap_or("");
+    let open: Vec<(String, String, Option<f64>)> = signals::question_lineages(snapshots)
+        .into_iter()
+        .filter(|l| {
+            l.section
+                .as_deref()
+                .is_some_and(|s| lineage::section_matches(s, &signals::OPEN_SECTIONS))
+        })
+        .map(|l| {
+            let age = l.age_days(latest);
+            (l.title, l.first_seen, age)
+        })
+        .collect();
+    let (resolved, abandoned) = signals::question_outcomes(snapshots).unwrap_or_default();
+
+    let mut lines = vec![format!("{} ({})", "Open".bold(), open.len())];
+    let width = title_width(open.iter().map(|q| q.0.as_str()));
+    lines.extend(open.iter().map(|(title, first_seen, age)| {
+        let age = age.map_or("?".to_string(), |d| format!("{d:.0}d"));
+        format!(
+            "  {title:<width$}  {age:>4}  {}",
+            format!("since {first_seen}").dimmed()
+        )
+    }));
+    for (label, moved) in [("Resolved", &resolved), ("Abandoned", &abandoned)] {
+        lines.push(format!("{} ({})", label.bold(), moved.len()));
+        lines.extend(moved.iter().map(|l| {
+            let to = l.section.as_deref().unwrap_or("deleted");
+            format!(
+                "  {}  {}",
+                l.title,
+                format!("→ {to} at {}", l.changed()).dimmed()
+            )
+        }));
+    }
+
+    let value = match signal {
+        "questions_resolved" => signals::questions_resolved(snapshots),
+        "questions_abandoned" => signals::questions_abandoned(snapshots),
+        _ => signals::open_question_age(snapshots),
+    };
+    let titles = |moved: &[Lineage]| moved.iter().map(|l| l.title.clone()).collect::<Vec<_>>();
+    Explanation {
+        value,
+        summary: match signal {
+            "open_question_age" => " (median days open)".to_string(),
+            _ => " (since the previous collection)".to_string(),
+        },
+        source: "snapshots.json — CURIOSITY.md questions followed by heading across collections"
+            .to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": signal,
+            "value": value,
+            "as_of": snapshots.last().map(|s| &s.timestamp),
+            "open": open
+                .iter()
+                .map(|(title, first_seen, age)| serde_json::json!({
+                    "title": title,
+                    "first_seen": first_seen,
+                    "age_days": age,
+                }))
+                .collect::<Vec<_>>(),
+            "resolved": titles(&resolved),
+            "abandoned": titles(&abandoned),
+        }),
+    }
+}
+
+/// Active thoughts with how long they've been active, and settled ones with
+/// how long they took. Read from snapshots, like `resolution`.
+fn thought_ages(signal: &str, snapshots: &[DocumentSnapshot], stale_after: usize) -> Explanation {
+    let lineages = signals::thought_lineages(snapshots);
+    let in_sections = |l: &Lineage, names: &[&str]| {
+        l.section
+            .as_deref()
+            .is_some_and(|s| lineage::section_matches(s, names))
+    };
+    let active: Vec<&Lineage> = lineages
+        .iter()
+        .filter(|l| in_sections(l, &signals::ACTIVE_THOUGHT_SECTIONS))
+        .collect();
+    let resolved: Vec<(&Lineage, f64)> = lineages
+        .iter()
+        .filter_map(|l| signals::resolution_days(l).map(|d| (l, d)))
+        .collect();
+    let stale = signals::stale_thought_lineages(snapshots, stale_after);
+
+    let width = title_width(lineages.iter().map(|l| l.title.as_str()));
+    let mut lines = vec![format!("{} ({})", "Active".bold(), active.len())];
+    lines.extend(active.iter().map(|l| {
+        let mark = if l.sessions_in_section > stale_after {
+            "!".red().to_string()
+        } else {
+            " ".to_string()
+        };
+        format!(
+            "{mark} {:<width$}  {:>3} sessions  {}",
+            l.title,
+            l.sessions_in_section,
+            format!("first seen {}", l.first_seen).dimmed()
+        )
+    }));
+    lines.push(format!("{} ({})", "Resolved".bold(), resolved.len()));
+    lines.extend(resolved.iter().map(|(l, days)| {
+        format!(
+            "  {:<width$}  {days:>5.1} days  {}",
+            l.title,
+            format!(
+                "→ {} at {}",
+                l.section.as_deref().unwrap_or("deleted"),
+                l.changed()
+            )
+            .dimmed()
+        )
+    }));
+
+    let value = match signal {
+        "stale_thoughts" => signals::stale_thoughts(snapshots, stale_after),
+        _ => signals::thought_resolution_time(snapshots),
+    };
+    let history = |l: &Lineage| {
+        serde_json::json!({
+            "title": l.title,
+            "section": l.section,
+            "first_seen": l.first_seen,
+            "sessions_in_section": l.sessions_in_section,
+            "changes": l
+                .changes
+                .iter()
+                .map(|c| serde_json::json!({ "timestamp": c.timestamp, "from": c.from, "to": c.to }))
+                .collect::<Vec<_>>(),
+        })
+    };
+    Explanation {
+        value,
+        summary: match signal {
+            "stale_thoughts" => format!(" (active for more than {stale_after} sessions)"),
+            _ => " (median days from first seen to graduated or dissolved)".to_string(),
+        },
+        source: "snapshots.json — THOUGHTS.md entries followed by heading across collections"
+            .to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": signal,
+            "value": value,
+            "stale_after_sessions": stale_after,
+            "stale": stale.iter().map(|l| l.title.clone()).collect::<Vec<_>>(),
+            "thoughts": lineages.iter().map(history).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn thoughts(thoughts: &str) -> Explanation {
+    let sections: Vec<(&str, Vec<String>)> = THOUGHT_SECTIONS
+        .iter()
+        .map(|&s| (s, parser::h3_titles_under_section(thoughts, s)))
+        .collect();
+    let count = |name: &str| {
+        sections
+            .iter()
+            .find(|(s, _)| *s == name)
+            .map_or(0, |(_, t)| t.len())
+    };
+    let resolved = count("Graduated") + count("Dissolved");
+    let total = resolved + count("Active");
+
+    let mut lines = Vec::new();
+    for (section, titles) in &sections {
+        lines.push(format!("{} ({})", section.bold(), titles.len()));
+        lines.extend(titles.iter().map(|t| format!("  {t}")));
+    }
+
+    Explanation {
+        value: signals::thought_lifecycle(thoughts),
+        summary: format!(" ((graduated + dissolved) / total = {resolved} / {total})"),
+        source: "THOUGHTS.md — Active, Graduated, Dissolved".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "thought_lifecycle",
+            "value": signals::thought_lifecycle(thoughts),
+            "sections": sections
+                .iter()
+                .map(|(s, titles)| (s.to_lowercase(), serde_json::json!(titles)))
+                .collect::<serde_json::Map<_, _>>(),
+        }),
+    }
+}
+
+fn vocabulary(reflections: &str) -> Explanation {
+    let text = parser::extract_section_text(reflections, &REFLECTION_SECTIONS);
+    let tokens = parser::tokenize(&text);
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for t in &tokens {
+        *counts.entry(t.as_str()).or_insert(0) += 1;
+    }
+    let mut repeated: Vec<(&str, usize)> = counts.iter().map(|(w, n)| (*w, *n)).collect();
+    repeated.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    repeated.truncate(TOP_WORDS);
+
+    let mut lines = vec!["Most repeated words:".to_string()];
+    lines.extend(repeated.iter().map(|(w, n)| format!("  {n:>4}  {w}")));
+
+    Explanation {
+        value: signals::vocabulary_diversity(reflections),
+        summary: format!(" ({} unique of {} words)", counts.len(), tokens.len()),
+        source: "REFLECTIONS.md — Observations, Patterns, Lessons".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "vocabulary_diversity",
+            "value": signals::vocabulary_diversity(reflections),
+            "tokens": tokens.len(),
+            "unique": counts.len(),
+            "top_words": repeated
+                .iter()
+                .map(|(w, n)| serde_json::json!({ "word": w, "count": n }))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn hedging(reflections: &str, lexicon: &HedgingLexicon) -> Explanation {
+    let text = parser::extract_section_text(reflections, &REFLECTION_SECTIONS);
+    let tokens = parser::tokenize(&text);
+    let hedges = signals::phrase_counts(&tokens, &lexicon.hedges);
+    let absolutes = signals::phrase_counts(&tokens, &lexicon.absolutes);
+    let total = |counts: &[(String, usize)]| counts.iter().map(|c| c.1).sum::<usize>();
+
+    let mut lines = Vec::new();
+    for (label, counts) in [("Hedges", &hedges), ("Absolutes", &absolutes)] {
+        lines.push(format!("{} ({})", label.bold(), total(counts)));
+        lines.extend(counts.iter().map(|(p, n)| format!("  {n:>4}  {p}")));
+    }
+
+    let value = signals::hedging_balance(reflections, lexicon);
+    let as_json = |counts: &[(String, usize)]| {
+        counts
+            .iter()
+            .map(|(p, n)| (p.clone(), serde_json::json!(n)))
+            .collect::<serde_json::Map<_, _>>()
+    };
+    Explanation {
+        value,
+        summary: format!(
+            " (({} hedges − {} absolutes) per 100 of {} words)",
+            total(&hedges),
+            total(&absolutes),
+            tokens.len()
+        ),
+        source: "REFLECTIONS.md — Observations, Patterns, Lessons".to_string(),
+        lines,
+        json: serde_json::json!({
+            "signal": "hedging_balance",
+            "value": value,
+            "tokens": tokens.len(),
+            "hedges": as_json(&hedges),
+            "absolutes": as_json(&absolutes),
+        }),
+    }
+}
+
+fn title_width<'a>(titles: impl Iterator<Item = &'a str>) -> usize {
+    titles.map(|t| t.chars().count()).max().unwrap_or(0).min(48)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grounding_lists_verdict_per_entry() {
+        let content =
+            "## Observations\n\n### Dated\nOn 2026-02-25 it rained.\n\n### Vague\nThings matter.\n";
+        let evidence = EvidenceMatcher::new(&state::default_evidence(), None).unwrap();
+        let e = grounding(content, &evidence);
+        assert_eq!(e.value, Some(0.5));
+        let entries = e.json["entries"].as_array().unwrap();
+        assert_eq!(entries[0]["markers"][0]["category"], "dates");
+        assert_eq!(entries[0]["markers"][0]["marker"], "2026-02-25");
+        assert_eq!(entries[1]["credit"], 0.0);
+        assert!(entries[1]["markers"].as_array().unwrap().is_empty());
+        assert_eq!(e.json["counts"]["dates"], 1);
+        assert_eq!(e.json["counts"]["urls"], 0);
+    }
+
+    #[test]
+    fn depth_scores_each_question() {
+        let content = "## Open Questions\n\n### Why?\n\n### Does it?\n";
+        let e = depth(content);
+        assert_eq!(e.value, Some(0.25));
+        assert_eq!(e.json["questions"][0]["kind"], "deep");
+        assert_eq!(e.json["questions"][1]["quality"], 0.0);
+        assert!(e.summary.contains("1 deep"));
+    }
+
+    #[test]
+    fn resolution_reads_recorded_snapshots() {
+        let snapshot = |ts: &str, sections: &[(&str, &str)]| DocumentSnapshot {
+            timestamp: ts.to_string(),
+            documents: [(
+                "CURIOSITY.md".to_string(),
+                sections
+                    .iter()
+                    .map(|(s, t)| (s.to_string(), vec![t.to_string()]))
+                    .collect(),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let snapshots = vec![
+            snapshot("2026-03-01T10:00:00Z", &[("Open Questions", "Why?")]),
+            snapshot("2026-03-03T10:00:00Z", &[("Explored", "Why?")]),
+        ];
+        let e = resolution("questions_resolved", &snapshots);
+        assert_eq!(e.value, Some(1.0));
+        assert_eq!(e.json["resolved"], serde_json::json!(["Why?"]));
+        assert!(e.json["open"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn thought_ages_flag_stale_thoughts() {
+        let snapshots: Vec<DocumentSnapshot> = (1..=3)
+            .map(|day| DocumentSnapshot {
+                timestamp: format!("2026-03-0{day}T10:00:00Z"),
+                documents: [(
+                    "THOUGHTS.md".to_string(),
+                    parser::h3_titles_by_section("## Active\n\n### Lingering\n"),
+                )]
+                .into_iter()
+                .collect(),
+            })
+            .collect();
+        let e = thought_ages("stale_thoughts", &snapshots, 2);
+        assert_eq!(e.value, Some(1.0));
+        assert_eq!(e.json["stale"], serde_json::json!(["Lingering"]));
+        assert_eq!(e.json["thoughts"][0]["sessions_in_section"], 3);
+        assert_eq!(e.json["thoughts"][0]["first_seen"], "2026-03-01T10:00:00Z");
+    }
+
+    #[test]
+    fn thoughts_list_entries_per_section() {
+        let content = "## Active\n\n### One\n\n## Graduated\n\n### Two\n\n### Three\n";
+        let e = thoughts(content);
+        assert_eq!(
+            e.json["sections"]["graduated"],
+            serde_json::json!(["Two", "Three"])
+        );
+        assert!(e.summary.contains("2 / 3"));
+    }
+}
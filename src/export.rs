@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use owo_colors::OwoColorize;
+
+use crate::state::{self, AlertLevel, Annotation, SignalVector};
+use crate::{alerts, analyze, annotations, epochs};
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Csv,
+    Jsonl,
+    Tsv,
+}
+
+/// Prefix for the per-document hash columns in CSV and TSV output.
+pub const HASH_PREFIX: &str = "hash:";
+
+/// One exported collection.
+struct Row<'a> {
+    vector: &'a SignalVector,
+    level: AlertLevel,
+    /// Notes of the annotations that map to this collection, joined by "; ".
+    annotation: String,
+}
+
+/// `vigil-echo export`: one row per collection for notebooks and spreadsheets.
+pub fn run(
+    format: Format,
+    since: Option<&str>,
+    until: Option<&str>,
+    signals: &[String],
+    out: Option<&Path>,
+) -> Result<(), String> {
+    let history = state::load_signals()?;
+    let config = state::load_config()?;
+    let resets = epochs::load_resets()?;
+    let annotations = state::load_annotations()?;
+
+    let columns = signal_columns(signals)?;
+    let since = since
+        .map(|s| state::parse_timestamp_arg(s, false))
+        .transpose()?;
+    let until = until
+        .map(|s| state::parse_timestamp_arg(s, true))
+        .transpose()?;
+    let start = since.as_deref().and_then(state::parse_iso_epoch);
+    let end = until.as_deref().and_then(state::parse_iso_epoch);
+
+    // Levels come from replaying the full history, so rows keep their context
+    let levels = alerts::replay(&history, &resets, &config);
+    let notes = annotation_notes(&history, &annotations);
+    let rows: Vec<Row> = history
+        .iter()
+        .zip(levels)
+        .zip(notes)
+        .filter(|((sv, _), _)| {
+            let t = state::parse_iso_epoch(&sv.timestamp);
+            start.is_none_or(|s| t.is_some_and(|t| t >= s))
+                && end.is_none_or(|e| t.is_some_and(|t| t <= e))
+        })
+        .map(|((vector, level), annotation)| Row {
+            vector,
+            level,
+            annotation,
+        })
+        .collect();
+
+    let text = match format {
+        Format::Csv => delimited(&rows, &columns, ','),
+        Format::Tsv => delimited(&rows, &columns, '\t'),
+        Format::Jsonl => jsonl(&rows, &columns)?,
+    };
+
+    match out {
+        Some(path) => {
+            fs::write(path, &text)
+                .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+            println!(
+                "{} Exported {} collection(s) to {}",
+                "✓".green(),
+                rows.len(),
+                path.display()
+            );
+        }
+        None => print!("{text}"),
+    }
+    Ok(())
+}
+
+/// Signals to export, in canonical order; all of them when none are named.
+fn signal_columns(requested: &[String]) -> Result<Vec<&'static str>, String> {
+    for name in requested {
+        if !analyze::SIGNAL_NAMES.contains(&name.as_str()) {
+            return Err(format!(
+                "Unknown signal '{name}' (expected one of: {})",
+                analyze::SIGNAL_NAMES.join(", ")
+            ));
+        }
+    }
+    Ok(analyze::SIGNAL_NAMES
+        .iter()
+        .copied()
+        .filter(|name| requested.is_empty() || requested.iter().any(|r| r == name))
+        .collect())
+}
+
+/// Annotation notes per collection, each annotation going to the first
+/// collection at or after it.
+fn annotation_notes(history: &[SignalVector], annotations: &[Annotation]) -> Vec<String> {
+    let mut notes: Vec<Vec<&str>> = vec![Vec::new(); history.len()];
+    for a in annotations {
+        if let Some(i) = annotations::collection_index(history, a) {
+            notes[i].push(&a.note);
+        }
+    }
+    notes.into_iter().map(|n| n.join("; ")).collect()
+}
+
+/// CSV or TSV with a header row. Document hashes get one column per document.
+fn delimited(rows: &[Row], columns: &[&str], sep: char) -> String {
+    let documents: BTreeSet<&str> = rows
+        .iter()
+        .flat_map(|r| r.vector.document_hashes.keys().map(String::as_str))
+        .collect();
+
+    let mut header: Vec<String> = vec!["timestamp".to_string(), "trigger".to_string()];
+    header.extend(columns.iter().map(|c| c.to_string()));
+    header.push("alert_level".to_string());
+    header.push("tags".to_string());
+    header.push("annotation".to_string());
+    header.extend(documents.iter().map(|d| format!("{HASH_PREFIX}{d}")));
+
+    let mut out = String::new();
+    push_record(&mut out, &header, sep);
+    for row in rows {
+        let sv = row.vector;
+        let mut record = vec![sv.timestamp.clone(), sv.trigger.clone()];
+        record.extend(columns.iter().map(|&c| {
+            analyze::get_signal(sv, c)
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        }));
+        record.push(alerts::label(row.level).to_string());
+        record.push(sv.tags.join(";"));
+        record.push(row.annotation.clone());
+        record.extend(
+            documents
+                .iter()
+                .map(|&d| sv.document_hashes.get(d).cloned().unwrap_or_default()),
+        );
+        push_record(&mut out, &record, sep);
+    }
+    out
+}
+
+fn push_record(out: &mut String, fields: &[String], sep: char) {
+    let fields: Vec<String> = fields.iter().map(|f| escape_field(f, sep)).collect();
+    out.push_str(&fields.join(&sep.to_string()));
+    out.push('\n');
+}
+
+/// CSV quotes fields that need it (RFC 4180); TSV has no quoting, so tabs
+/// and line breaks become spaces.
+fn escape_field(field: &str, sep: char) -> String {
+    if sep == '\t' {
+        return field.replace(['\t', '\n', '\r'], " ");
+    }
+    if field.contains([sep, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One JSON object per line, with signal values as top-level keys.
+fn jsonl(rows: &[Row], columns: &[&str]) -> Result<String, String> {
+    let mut out = String::new();
+    for r in rows {
+        let sv = r.vector;
+        let mut row = serde_json::Map::new();
+        row.insert("timestamp".to_string(), serde_json::json!(sv.timestamp));
+        row.insert("trigger".to_string(), serde_json::json!(sv.trigger));
+        for &c in columns {
+            row.insert(c.to_string(), serde_json::json!(analyze::get_signal(sv, c)));
+        }
+        row.insert(
+            "alert_level".to_string(),
+            serde_json::json!(alerts::label(r.level)),
+        );
+        row.insert("tags".to_string(), serde_json::json!(sv.tags));
+        let annotation = (!r.annotation.is_empty()).then_some(&r.annotation);
+        row.insert("annotation".to_string(), serde_json::json!(annotation));
+        let hashes: BTreeMap<&String, &String> = sv.document_hashes.iter().collect();
+        row.insert("document_hashes".to_string(), serde_json::json!(hashes));
+        let line =
+            serde_json::to_string(&row).map_err(|e| format!("JSON serialization failed: {e}"))?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(ts: &str, vd: Option<f64>, hashes: &[(&str, &str)]) -> SignalVector {
+        SignalVector {
+            timestamp: ts.to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: vd,
+                question_generation: Some(2.0),
+                ..Signals::default()
+            },
+            document_hashes: hashes
+                .iter()
+                .map(|(d, h)| (d.to_string(), h.to_string()))
+                .collect::<HashMap<_, _>>(),
+            tags: vec!["topic:a".to_string(), "topic:b".to_string()],
+        }
+    }
+
+    #[test]
+    fn csv_has_one_column_per_document() {
+        let a = make_vector("2026-03-01T10:00:00Z", Some(0.5), &[("A.md", "aa")]);
+        let b = make_vector("2026-03-02T10:00:00Z", None, &[("B.md", "bb")]);
+        let rows = vec![
+            Row {
+                vector: &a,
+                level: AlertLevel::Healthy,
+                annotation: String::new(),
+            },
+            Row {
+                vector: &b,
+                level: AlertLevel::Watch,
+                annotation: String::new(),
+            },
+        ];
+        let columns = signal_columns(&["vocabulary_diversity".to_string()]).unwrap();
+        let csv = delimited(&rows, &columns, ',');
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "timestamp,trigger,vocabulary_diversity,alert_level,tags,annotation,hash:A.md,hash:B.md"
+        );
+        assert_eq!(
+            lines[1],
+            "2026-03-01T10:00:00Z,test,0.5,HEALTHY,topic:a;topic:b,,aa,"
+        );
+        assert_eq!(
+            lines[2],
+            "2026-03-02T10:00:00Z,test,,WATCH,topic:a;topic:b,,,bb"
+        );
+    }
+
+    #[test]
+    fn annotations_mark_the_next_collection() {
+        let history = vec![
+            make_vector("2026-03-01T10:00:00Z", Some(0.5), &[]),
+            make_vector("2026-03-02T10:00:00Z", Some(0.5), &[]),
+        ];
+        let note = |ts: &str, note: &str| Annotation {
+            timestamp: ts.to_string(),
+            note: note.to_string(),
+            reset_baseline: false,
+        };
+        let annotations = vec![
+            note("2026-03-01T12:00:00Z", "new model"),
+            note("2026-03-02T09:00:00Z", "prompt change"),
+            note("2026-03-05T00:00:00Z", "after the last collection"),
+        ];
+        let notes = annotation_notes(&history, &annotations);
+        assert_eq!(notes, vec!["", "new model; prompt change"]);
+
+        let rows: Vec<Row> = history
+            .iter()
+            .zip(notes)
+            .map(|(vector, annotation)| Row {
+                vector,
+                level: AlertLevel::Healthy,
+                annotation,
+            })
+            .collect();
+        let lines = jsonl(&rows, &[]).unwrap();
+        let parsed: Vec<serde_json::Value> = lines
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(parsed[0]["annotation"], serde_json::Value::Null);
+        assert_eq!(parsed[1]["annotation"], "new model; prompt change");
+        let csv = delimited(&rows, &[], ',');
+        assert!(csv
+            .lines()
+            .nth(2)
+            .unwrap()
+            .contains(",new model; prompt change"));
+    }
+
+    #[test]
+    fn fields_are_escaped_per_format() {
+        assert_eq!(escape_field("a,b", ','), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("a\tb", '\t'), "a b");
+        assert_eq!(escape_field("a,b", '\t'), "a,b");
+    }
+
+    #[test]
+    fn unknown_signals_are_rejected() {
+        assert!(signal_columns(&["mood".to_string()]).is_err());
+        assert_eq!(signal_columns(&[]).unwrap(), analyze::SIGNAL_NAMES.to_vec());
+    }
+}
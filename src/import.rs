@@ -0,0 +1,325 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use owo_colors::OwoColorize;
+
+use crate::export::HASH_PREFIX;
+use crate::state::{self, SignalVector, Signals};
+use crate::{alerts, analyze, epochs};
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Strategy {
+    /// Add vectors that aren't already in the history
+    Merge,
+    /// Discard the current history and use the file's vectors
+    Replace,
+}
+
+/// Outcome of combining two histories.
+#[derive(Debug)]
+struct Merged {
+    history: Vec<SignalVector>,
+    added: usize,
+    skipped: usize,
+}
+
+/// `vigil-echo import`: bring in history from another machine.
+pub fn run(file: &Path, strategy: Strategy) -> Result<(), String> {
+    let content =
+        fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {e}", file.display()))?;
+    let incoming = parse(&content)?;
+    let config = state::load_config()?;
+    let existing = state::load_signals()?;
+    let previous = existing.len();
+
+    let merged = match strategy {
+        Strategy::Merge => merge(existing, incoming),
+        Strategy::Replace => merge(Vec::new(), incoming),
+    };
+    let mut history = merged.history;
+    let trimmed = history.len().saturating_sub(config.max_history);
+    history.drain(..trimmed);
+    state::save_signals(&history)?;
+
+    let resets = epochs::load_resets()?;
+    let mut analysis = analyze::run(&history, &resets, &config);
+    alerts::apply(&mut analysis, &history, &config)?;
+    state::save_analysis(&analysis)?;
+
+    println!(
+        "{} Imported {} ({})",
+        "✓".green(),
+        file.display(),
+        match strategy {
+            Strategy::Merge => "merge",
+            Strategy::Replace => "replace",
+        }
+    );
+    if strategy == Strategy::Replace {
+        println!("  Replaced:  {previous} existing vector(s)");
+    }
+    println!("  Added:     {}", merged.added);
+    println!("  Skipped:   {} duplicate(s)", merged.skipped);
+    if trimmed > 0 {
+        println!(
+            "  Trimmed:   {trimmed} oldest vector(s) over max_history ({})",
+            config.max_history
+        );
+    }
+    println!("  History:   {} data points", history.len());
+    Ok(())
+}
+
+/// Read either signals.json (a JSON array) or the CSV/TSV written by `export`.
+fn parse(content: &str) -> Result<Vec<SignalVector>, String> {
+    if content.trim_start().starts_with('[') {
+        let vectors: Vec<SignalVector> =
+            serde_json::from_str(content).map_err(|e| format!("Failed to parse signals: {e}"))?;
+        for (i, sv) in vectors.iter().enumerate() {
+            check_timestamp(&sv.timestamp).map_err(|e| format!("Vector {}: {e}", i + 1))?;
+        }
+        return Ok(vectors);
+    }
+    let first = content.lines().next().unwrap_or_default();
+    if !first.split([',', '\t']).any(|c| c == "timestamp") {
+        return Err(
+            "Unrecognized file: expected a signals.json array or CSV/TSV from `vigil-echo export`"
+                .to_string(),
+        );
+    }
+    let sep = if first.contains('\t') { '\t' } else { ',' };
+    parse_delimited(content, sep)
+}
+
+fn parse_delimited(content: &str, sep: char) -> Result<Vec<SignalVector>, String> {
+    let mut records = split_records(content, sep).into_iter();
+    let header = records.next().unwrap_or_default();
+
+    let mut vectors = Vec::new();
+    for (i, record) in records.enumerate() {
+        if record.iter().all(|f| f.is_empty()) {
+            continue;
+        }
+        let row = i + 2;
+        let fields: HashMap<&str, &str> = header
+            .iter()
+            .map(String::as_str)
+            .zip(record.iter().map(String::as_str))
+            .collect();
+        let timestamp = fields.get("timestamp").copied().unwrap_or_default();
+        check_timestamp(timestamp).map_err(|e| format!("Row {row}: {e}"))?;
+
+        let mut signals = Signals::default();
+        for &name in analyze::SIGNAL_NAMES {
+            let Some(raw) = fields.get(name).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            let value: f64 = raw
+                .parse()
+                .map_err(|_| format!("Row {row}: invalid {name} value '{raw}'"))?;
+            signals.set(name, Some(value));
+        }
+
+        vectors.push(SignalVector {
+            timestamp: timestamp.to_string(),
+            trigger: fields
+                .get("trigger")
+                .filter(|t| !t.is_empty())
+                .unwrap_or(&"import")
+                .to_string(),
+            signals,
+            document_hashes: fields
+                .iter()
+                .filter(|(_, hash)| !hash.is_empty())
+                .filter_map(|(column, hash)| {
+                    let document = column.strip_prefix(HASH_PREFIX)?;
+                    Some((document.to_string(), hash.to_string()))
+                })
+                .collect(),
+            tags: fields
+                .get("tags")
+                .map(|t| {
+                    t.split(';')
+                        .filter(|t| !t.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        });
+    }
+    Ok(vectors)
+}
+
+/// A full "YYYY-MM-DDThh:mm:ssZ" timestamp, as `collect` writes them.
+fn check_timestamp(timestamp: &str) -> Result<(), String> {
+    if timestamp.len() != 20 {
+        return Err(format!(
+            "invalid timestamp '{timestamp}' (expected YYYY-MM-DDThh:mm:ssZ)"
+        ));
+    }
+    state::parse_timestamp_arg(timestamp, false).map(|_| ())
+}
+
+/// Split delimited text into records. CSV fields may be quoted (RFC 4180),
+/// including doubled quotes and line breaks; TSV fields never are.
+fn split_records(content: &str, sep: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if sep != '\t' && field.is_empty() => quoted = true,
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c if c == sep => record.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Two vectors are the same collection when their timestamps and document
+/// hashes both match.
+fn key(sv: &SignalVector) -> (String, BTreeMap<String, String>) {
+    let hashes = sv
+        .document_hashes
+        .iter()
+        .map(|(d, h)| (d.clone(), h.clone()))
+        .collect();
+    (sv.timestamp.clone(), hashes)
+}
+
+/// Add incoming vectors not already present, then sort chronologically.
+fn merge(existing: Vec<SignalVector>, incoming: Vec<SignalVector>) -> Merged {
+    let mut seen: HashSet<_> = existing.iter().map(key).collect();
+    let mut history = existing;
+    let (mut added, mut skipped) = (0, 0);
+    for sv in incoming {
+        if seen.insert(key(&sv)) {
+            history.push(sv);
+            added += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+    history.sort_by_key(|sv| state::parse_iso_epoch(&sv.timestamp));
+    Merged {
+        history,
+        added,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_vector(ts: &str, hash: &str) -> SignalVector {
+        SignalVector {
+            timestamp: ts.to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(0.5),
+                ..Signals::default()
+            },
+            document_hashes: [("REFLECTIONS.md".to_string(), hash.to_string())]
+                .into_iter()
+                .collect(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn merge_dedupes_on_timestamp_and_hashes() {
+        let existing = vec![
+            make_vector("2026-03-01T10:00:00Z", "a"),
+            make_vector("2026-03-03T10:00:00Z", "c"),
+        ];
+        let incoming = vec![
+            make_vector("2026-03-03T10:00:00Z", "c"),
+            make_vector("2026-03-02T10:00:00Z", "b"),
+            make_vector("2026-03-01T10:00:00Z", "other"),
+        ];
+        let merged = merge(existing, incoming);
+        assert_eq!(merged.added, 2);
+        assert_eq!(merged.skipped, 1);
+        let order: Vec<&str> = merged
+            .history
+            .iter()
+            .map(|sv| sv.timestamp.as_str())
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                "2026-03-01T10:00:00Z",
+                "2026-03-01T10:00:00Z",
+                "2026-03-02T10:00:00Z",
+                "2026-03-03T10:00:00Z"
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_exported_csv() {
+        let csv = "timestamp,trigger,vocabulary_diversity,evidence_grounding,alert_level,tags,hash:REFLECTIONS.md\n\
+                   2026-03-01T10:00:00Z,\"session, end\",0.5,,WATCH,topic:a;topic:b,abc\n";
+        let vectors = parse(csv).unwrap();
+        assert_eq!(vectors.len(), 1);
+        let sv = &vectors[0];
+        assert_eq!(sv.trigger, "session, end");
+        assert_eq!(sv.signals.vocabulary_diversity, Some(0.5));
+        assert_eq!(sv.signals.evidence_grounding, None);
+        assert_eq!(sv.tags, vec!["topic:a", "topic:b"]);
+        assert_eq!(sv.document_hashes["REFLECTIONS.md"], "abc");
+    }
+
+    #[test]
+    fn reads_signals_json_and_rejects_other_files() {
+        let json = serde_json::to_string(&vec![make_vector("2026-03-01T10:00:00Z", "a")]).unwrap();
+        assert_eq!(parse(&json).unwrap().len(), 1);
+        assert!(parse("hello\nworld\n").is_err());
+        assert!(parse("timestamp\tvocabulary_diversity\nyesterday\t0.5\n").is_err());
+    }
+
+    #[test]
+    fn malformed_timestamps_are_rejected_not_panicked_on() {
+        for ts in [
+            "2026é03-01T10:00:00Z",
+            "1960-03-01T10:00:00Z",
+            "2026-03-01",
+            "2026-02-31T99:00:00Z",
+            "2026-02-31T10:00:00Z",
+            "2026-04-31T10:00:00Z",
+        ] {
+            let csv =
+                format!("timestamp,vocabulary_diversity\n2026-03-01T10:00:00Z,0.5\n{ts},0.5\n");
+            let err = parse(&csv).unwrap_err();
+            assert!(err.starts_with("Row 3:"), "{ts}: {err}");
+        }
+        let mut sv = make_vector("2026-03-01T10:00:00Z", "a");
+        sv.timestamp = "2026é03-01T10:00:00Z".to_string();
+        let json = serde_json::to_string(&vec![sv]).unwrap();
+        assert!(parse(&json).unwrap_err().starts_with("Vector 1:"));
+    }
+}
@@ -229,11 +229,19 @@ pub fn run() -> Result<(), String> {
     // Summary
     println!(
         "\n{} Metacognitive monitoring is ready.\n\n\
-         \x20 Signals tracked (Phase 1):\n\
-         \x20   vocabulary_diversity  — Lexical variety in reflections\n\
-         \x20   question_generation   — Active curiosity level\n\
-         \x20   thought_lifecycle     — Thought turnover health\n\
-         \x20   evidence_grounding    — Concrete reference density\n\n\
+         \x20 Signals tracked:\n\
+         \x20   vocabulary_diversity    — Lexical variety in reflections\n\
+         \x20   question_generation     — Active curiosity level\n\
+         \x20   thought_lifecycle       — Thought turnover health\n\
+         \x20   evidence_grounding      — Concrete reference density\n\
+         \x20   hedging_balance         — Hedges vs absolutes in reflections\n\
+         \x20   question_depth          — Why/how questions with exploration\n\
+         \x20   questions_opened        — New questions since last collection\n\
+         \x20   questions_resolved      — Questions moved to Explored\n\
+         \x20   questions_abandoned     — Questions deleted unexplored\n\
+         \x20   open_question_age       — Median days a question stays open\n\
+         \x20   thought_resolution_time — Median days until a thought settles\n\
+         \x20   stale_thoughts          — Thoughts stuck in Active\n\n\
          \x20 Hooks installed:\n\
          \x20   PreToolUse → vigil-echo pulse    (inject cognitive health)\n\
          \x20   SessionEnd → vigil-echo collect   (extract signals)\n\n\
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use owo_colors::OwoColorize;
+
+use crate::state::{self, Config, SignalVector};
+use crate::{analyze, stats};
+
+/// How a tag's sessions were followed, in composite health points.
+#[derive(Debug)]
+struct TagEffect {
+    tag: String,
+    sessions: usize,
+    mean_delta: f64,
+}
+
+pub fn run(horizon: usize, min_sessions: usize, json_output: bool) -> Result<(), String> {
+    let config = state::load_config()?;
+    let history = state::load_signals()?;
+    let horizon = horizon.max(1);
+    let (effects, baseline) = tag_effects(&history, &config, horizon);
+    let (ranked, sparse): (Vec<&TagEffect>, Vec<&TagEffect>) = effects
+        .iter()
+        .partition(|e| e.sessions >= min_sessions.max(1));
+
+    if json_output {
+        let output = serde_json::json!({
+            "horizon": horizon,
+            "baseline_delta": baseline,
+            "tags": ranked
+                .iter()
+                .map(|e| serde_json::json!({
+                    "tag": e.tag,
+                    "sessions": e.sessions,
+                    "mean_delta": e.mean_delta,
+                }))
+                .collect::<Vec<_>>(),
+        });
+        let json_str = serde_json::to_string_pretty(&output)
+            .map_err(|e| format!("JSON serialization failed: {e}"))?;
+        println!("{json_str}");
+        return Ok(());
+    }
+
+    println!();
+    println!("  {} — input attribution", "vigil-echo".bold());
+    println!();
+    let follow = if horizon == 1 {
+        "the next session".to_string()
+    } else {
+        format!("the next {horizon} sessions")
+    };
+    println!("  Change in composite health over {follow}, by tag");
+    if let Some(b) = baseline {
+        println!("  Baseline across all sessions: {:+.1}", b);
+    }
+
+    println!();
+    if ranked.is_empty() {
+        println!(
+            "    No tags with {}+ scored sessions yet. Tag sessions with `vigil-echo collect --tag topic:...`.",
+            min_sessions.max(1)
+        );
+    }
+    for e in &ranked {
+        let delta = format!("{:>+6.1}", e.mean_delta);
+        let delta = if e.mean_delta > 0.0 {
+            format!("{}", delta.green())
+        } else if e.mean_delta < 0.0 {
+            format!("{}", delta.red())
+        } else {
+            delta
+        };
+        let vs = baseline
+            .map(|b| format!("  ({:+.1} vs baseline)", e.mean_delta - b))
+            .unwrap_or_default();
+        println!(
+            "    {:<28} {}  {:>3} sessions{}",
+            e.tag,
+            delta,
+            e.sessions,
+            vs.dimmed()
+        );
+    }
+    if !sparse.is_empty() {
+        println!();
+        println!(
+            "    {}",
+            format!(
+                "{} more tag(s) with fewer than {} sessions: {}",
+                sparse.len(),
+                min_sessions.max(1),
+                sparse
+                    .iter()
+                    .map(|e| e.tag.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Mean follow-up composite change per tag, best first, plus the same
+/// change averaged over every session as a baseline.
+fn tag_effects(
+    history: &[SignalVector],
+    config: &Config,
+    horizon: usize,
+) -> (Vec<TagEffect>, Option<f64>) {
+    let scores: Vec<Option<f64>> = history
+        .iter()
+        .map(|sv| analyze::composite_score(sv, config))
+        .collect();
+
+    let mut by_tag: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    let mut all = Vec::new();
+    for (i, sv) in history.iter().enumerate() {
+        let Some(delta) = follow_up_delta(&scores, i, horizon) else {
+            continue;
+        };
+        all.push(delta);
+        for tag in &sv.tags {
+            by_tag.entry(tag).or_default().push(delta);
+        }
+    }
+
+    let mut effects: Vec<TagEffect> = by_tag
+        .into_iter()
+        .filter_map(|(tag, deltas)| {
+            Some(TagEffect {
+                tag: tag.to_string(),
+                sessions: deltas.len(),
+                mean_delta: stats::mean(&deltas)?,
+            })
+        })
+        .collect();
+    effects.sort_by(|a, b| b.mean_delta.total_cmp(&a.mean_delta));
+    (effects, stats::mean(&all))
+}
+
+/// Mean composite over the `horizon` sessions after `index`, minus its own.
+fn follow_up_delta(scores: &[Option<f64>], index: usize, horizon: usize) -> Option<f64> {
+    let own = scores.get(index).copied().flatten()?;
+    let end = (index + 1 + horizon).min(scores.len());
+    let after: Vec<f64> = scores
+        .get(index + 1..end)?
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+    Some(stats::mean(&after)? - own)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(vd: f64, tags: &[&str]) -> SignalVector {
+        SignalVector {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(vd),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn follow_up_uses_next_sessions() {
+        let scores = [Some(50.0), Some(60.0), None, Some(80.0)];
+        assert_eq!(follow_up_delta(&scores, 0, 1), Some(10.0));
+        assert_eq!(follow_up_delta(&scores, 0, 3), Some(20.0));
+        assert_eq!(follow_up_delta(&scores, 1, 1), None);
+        assert_eq!(follow_up_delta(&scores, 3, 1), None);
+    }
+
+    #[test]
+    fn ranks_tags_by_following_change() {
+        let history = vec![
+            make_vector(0.20, &["source:paper"]),
+            make_vector(0.35, &["source:feed"]),
+            make_vector(0.25, &["source:paper"]),
+            make_vector(0.35, &[]),
+        ];
+        let (effects, baseline) = tag_effects(&history, &Config::default(), 1);
+        assert_eq!(effects[0].tag, "source:paper");
+        assert_eq!(effects[0].sessions, 2);
+        assert!(effects[0].mean_delta > 0.0);
+        assert!(effects[1].mean_delta < 0.0);
+        assert!(baseline.is_some());
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::parser;
+use crate::state::{self, DocumentSnapshot};
+
+/// One ### entry of a document, followed across snapshots by its
+/// normalized title (see `parser::normalize_title`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lineage {
+    /// The title as last written.
+    pub title: String,
+    /// Timestamp of the first snapshot containing the entry.
+    pub first_seen: String,
+    /// Section the entry is in now; None once it has been deleted.
+    pub section: Option<String>,
+    /// Every move between sections, including into the document (from None)
+    /// and out of it (to None), oldest first.
+    pub changes: Vec<SectionChange>,
+    /// Consecutive snapshots the entry has spent in its current section.
+    pub sessions_in_section: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SectionChange {
+    pub timestamp: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl Lineage {
+    /// Timestamp of the latest move, into its current section or out of the
+    /// document.
+    pub fn changed(&self) -> &str {
+        self.changes
+            .last()
+            .map_or(self.first_seen.as_str(), |c| c.timestamp.as_str())
+    }
+
+    /// Days from first seen to `until`, or None when either timestamp is
+    /// unreadable.
+    pub fn age_days(&self, until: &str) -> Option<f64> {
+        days_between(&self.first_seen, until)
+    }
+}
+
+/// Follow every ### entry of `document` through `snapshots` (oldest first).
+/// Entries are returned in the order they first appeared. A snapshot that
+/// lacks the document altogether (it was missing or empty) is skipped
+/// rather than read as every entry being deleted.
+pub fn trace(snapshots: &[DocumentSnapshot], document: &str) -> Vec<Lineage> {
+    let mut lineages: Vec<Lineage> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for snapshot in snapshots {
+        let Some(sections) = snapshot.documents.get(document) else {
+            continue;
+        };
+        let present: HashSet<String> = sections
+            .iter()
+            .flat_map(|(_, titles)| titles)
+            .map(|t| parser::normalize_title(t))
+            .collect();
+
+        for (key, &i) in &index {
+            let lineage = &mut lineages[i];
+            if lineage.section.is_some() && !present.contains(key) {
+                lineage.changes.push(SectionChange {
+                    timestamp: snapshot.timestamp.clone(),
+                    from: lineage.section.take(),
+                    to: None,
+                });
+                lineage.sessions_in_section = 0;
+            }
+        }
+
+        // A title repeated within one snapshot counts in the section where it
+        // first appears in the document
+        let mut seen: HashSet<String> = HashSet::new();
+        for (section, titles) in sections {
+            for title in titles {
+                let key = parser::normalize_title(title);
+                if key.is_empty() || !seen.insert(key.clone()) {
+                    continue;
+                }
+                match index.get(&key) {
+                    Some(&i) => {
+                        let lineage = &mut lineages[i];
+                        lineage.title = title.clone();
+                        if lineage.section.as_deref() == Some(section.as_str()) {
+                            lineage.sessions_in_section += 1;
+                        } else {
+                            lineage.changes.push(SectionChange {
+                                timestamp: snapshot.timestamp.clone(),
+                                from: lineage.section.replace(section.clone()),
+                                to: Some(section.clone()),
+                            });
+                            lineage.sessions_in_section = 1;
+                        }
+                    }
+                    None => {
+                        index.insert(key, lineages.len());
+                        lineages.push(Lineage {
+                            title: title.clone(),
+                            first_seen: snapshot.timestamp.clone(),
+                            section: Some(section.clone()),
+                            changes: vec![SectionChange {
+                                timestamp: snapshot.timestamp.clone(),
+                                from: None,
+                                to: Some(section.clone()),
+                            }],
+                            sessions_in_section: 1,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    lineages
+}
+
+/// True when `section` contains any of `names`, ignoring case.
+pub fn section_matches(section: &str, names: &[&str]) -> bool {
+    let lower = section.to_lowercase();
+    names.iter().any(|n| lower.contains(n))
+}
+
+/// Days between two ISO timestamps.
+pub fn days_between(from: &str, to: &str) -> Option<f64> {
+    let from = state::parse_iso_epoch(from)?;
+    let to = state::parse_iso_epoch(to)?;
+    Some(to.saturating_sub(from) as f64 / 86_400.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(day: u32, sections: &[(&str, &[&str])]) -> DocumentSnapshot {
+        let sections = sections
+            .iter()
+            .map(|(s, titles)| {
+                (
+                    s.to_string(),
+                    titles.iter().map(|t| t.to_string()).collect(),
+                )
+            })
+            .collect();
+        DocumentSnapshot {
+            timestamp: format!("2026-03-{day:02}T10:00:00Z"),
+            documents: [("THOUGHTS.md".to_string(), sections)]
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn follows_entries_between_sections() {
+        let snapshots = vec![
+            snapshot(1, &[("Active", &["Idea one", "Idea two"])]),
+            snapshot(2, &[("Active", &["Idea one", "Idea two"])]),
+            snapshot(
+                4,
+                &[("Active", &["Idea One!"]), ("Graduated", &["Idea two"])],
+            ),
+            snapshot(5, &[("Active", &["Idea three"])]),
+        ];
+        let lineages = trace(&snapshots, "THOUGHTS.md");
+        assert_eq!(lineages.len(), 3);
+
+        let one = &lineages[0];
+        assert_eq!(one.title, "Idea One!");
+        assert_eq!(one.section, None);
+        assert_eq!(one.changed(), "2026-03-05T10:00:00Z");
+        assert_eq!(one.age_days("2026-03-05T10:00:00Z"), Some(4.0));
+
+        let two = &lineages[1];
+        assert_eq!(two.section, None);
+        assert_eq!(two.changes.len(), 3);
+        assert_eq!(two.changes[1].from.as_deref(), Some("Active"));
+        assert_eq!(two.changes[1].to.as_deref(), Some("Graduated"));
+        assert_eq!(two.changes[2].from.as_deref(), Some("Graduated"));
+        assert_eq!(two.changes[1].timestamp, "2026-03-04T10:00:00Z");
+
+        let three = &lineages[2];
+        assert_eq!(three.first_seen, "2026-03-05T10:00:00Z");
+        assert_eq!(three.sessions_in_section, 1);
+    }
+
+    #[test]
+    fn snapshots_without_the_document_are_skipped() {
+        let mut missing = snapshot(2, &[]);
+        missing.documents.clear();
+        let snapshots = vec![
+            snapshot(1, &[("Active", &["Idea"])]),
+            missing,
+            snapshot(3, &[("Active", &["Idea"])]),
+        ];
+        let lineages = trace(&snapshots, "THOUGHTS.md");
+        assert_eq!(lineages[0].changes.len(), 1);
+        assert_eq!(lineages[0].sessions_in_section, 2);
+    }
+
+    #[test]
+    fn repeated_titles_stay_in_their_first_section() {
+        let snapshots = vec![
+            snapshot(1, &[("Open Questions", &["Why?"])]),
+            snapshot(2, &[("Open Questions", &["Why?"]), ("Explored", &["Why?"])]),
+        ];
+        let lineages = trace(&snapshots, "THOUGHTS.md");
+        assert_eq!(lineages[0].section.as_deref(), Some("Open Questions"));
+        assert_eq!(lineages[0].changes.len(), 1);
+        assert_eq!(lineages[0].sessions_in_section, 2);
+    }
+}
@@ -1,9 +1,21 @@
+mod alerts;
 mod analyze;
+mod annotations;
+mod backfill;
+mod calibrate;
 mod collect;
+mod correlate;
+mod epochs;
+mod explain;
+mod export;
+mod import;
 mod init;
+mod insights;
+mod lineage;
 mod parser;
 mod paths;
 mod pulse;
+mod report;
 mod signals;
 mod state;
 mod stats;
@@ -32,6 +44,9 @@ enum Commands {
         /// What triggered this collection
         #[arg(long, default_value = "manual")]
         trigger: String,
+        /// Tag this session's inputs, e.g. --tag topic:philosophy (repeatable)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
     },
     /// Analyze signal trends over rolling window
     Analyze {
@@ -47,6 +62,157 @@ enum Commands {
         #[arg(long)]
         json: bool,
     },
+    /// Propose thresholds and health zones from observed history
+    Calibrate {
+        /// Percentile of window deltas used as the decline threshold
+        #[arg(long, default_value = "10")]
+        decline_pct: f64,
+        /// Percentile of window deltas used as the improve threshold
+        #[arg(long, default_value = "90")]
+        improve_pct: f64,
+        /// Percentile of values below which a signal is in the red zone
+        #[arg(long, default_value = "10")]
+        red_pct: f64,
+        /// Percentile of values below which a signal is in the yellow zone
+        #[arg(long, default_value = "25")]
+        yellow_pct: f64,
+        /// Write the proposal to config.json without asking
+        #[arg(long)]
+        write: bool,
+    },
+    /// Show the alert state and its transition history
+    Alerts {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Number of most recent transitions to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+    /// Show which document entries drove a signal's current score
+    Explain {
+        /// Signal to explain (e.g. evidence_grounding)
+        signal: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Correlate signals with each other, same-session and lagged
+    Correlate {
+        /// Correlation method
+        #[arg(long, value_enum, default_value = "spearman")]
+        method: correlate::Method,
+        /// Collections between leading and following signal
+        #[arg(long, default_value = "1")]
+        lag: usize,
+        /// Absolute same-session correlation at which a pair is flagged redundant
+        #[arg(long, default_value = "0.9")]
+        redundant: f64,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Rank session tags by the change in composite health that follows them
+    Insights {
+        /// Sessions after a tagged one to average over
+        #[arg(long, default_value = "1")]
+        horizon: usize,
+        /// Fewest scored sessions before a tag is ranked
+        #[arg(long, default_value = "2")]
+        min_sessions: usize,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Write a Markdown or HTML report for a period
+    Report {
+        /// Output format; HTML is a single offline file with SVG charts
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: report::Format,
+        /// Period to cover, ending at --until or the newest collection
+        #[arg(long, value_enum, default_value = "week")]
+        period: report::Period,
+        /// Start of a custom period (YYYY-MM-DD or full ISO timestamp)
+        #[arg(long)]
+        since: Option<String>,
+        /// End of the period (default: newest collection)
+        #[arg(long)]
+        until: Option<String>,
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        out: Option<std::path::PathBuf>,
+    },
+    /// Export one row per collection as CSV, JSONL or TSV
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "csv")]
+        format: export::Format,
+        /// First collection to include (YYYY-MM-DD or full ISO timestamp)
+        #[arg(long)]
+        since: Option<String>,
+        /// Last collection to include (a bare date includes that whole day)
+        #[arg(long)]
+        until: Option<String>,
+        /// Comma-separated signals to include (default: all)
+        #[arg(long, value_delimiter = ',')]
+        signals: Vec<String>,
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        out: Option<std::path::PathBuf>,
+    },
+    /// Seed history from the git log of the identity documents
+    Backfill {
+        /// Repository holding REFLECTIONS.md, THOUGHTS.md and CURIOSITY.md
+        #[arg(long)]
+        git: std::path::PathBuf,
+    },
+    /// Import history from signals.json or an exported CSV/TSV
+    Import {
+        /// File to import
+        file: std::path::PathBuf,
+        /// Merge into the current history, or replace it
+        #[arg(long, value_enum, default_value = "merge")]
+        strategy: import::Strategy,
+    },
+    /// Mark a known event (prompt change, model upgrade, ...) on the timeline
+    Annotate {
+        /// What happened
+        note: String,
+        /// When it happened (YYYY-MM-DD or full ISO timestamp; default now)
+        #[arg(long)]
+        at: Option<String>,
+        /// Restart trend baselines from this point
+        #[arg(long)]
+        reset_baseline: bool,
+    },
+    /// Start or list named epochs (trend baselines restart at each epoch)
+    Epoch {
+        #[command(subcommand)]
+        action: EpochAction,
+    },
+    /// Acknowledge the current alert (or one signal) to quiet pulse
+    Ack {
+        /// Snooze until this date (YYYY-MM-DD or full ISO timestamp)
+        #[arg(long)]
+        until: Option<String>,
+        /// Acknowledge only this signal
+        #[arg(long)]
+        signal: Option<String>,
+        /// Why the condition is acknowledged
+        #[arg(long)]
+        note: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum EpochAction {
+    /// Start a new epoch now
+    Start {
+        /// Name of the epoch, e.g. "v2 prompts"
+        name: String,
+    },
+    /// List epochs with per-signal statistics for comparison
+    List,
 }
 
 fn main() {
@@ -54,7 +220,7 @@ fn main() {
 
     let result = match cli.command {
         Some(Commands::Init) | None => init::run(),
-        Some(Commands::Collect { trigger }) => collect::run(&trigger),
+        Some(Commands::Collect { trigger, tags }) => collect::run(&trigger, &tags),
         Some(Commands::Analyze { window }) => {
             let config = match state::load_config() {
                 Ok(mut c) => {
@@ -73,7 +239,18 @@ fn main() {
                     std::process::exit(1);
                 }
             };
-            let analysis = analyze::run(&history, &config);
+            let resets = match epochs::load_resets() {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("{} {e}", "✗".red());
+                    std::process::exit(1);
+                }
+            };
+            let mut analysis = analyze::run(&history, &resets, &config);
+            if let Err(e) = alerts::apply(&mut analysis, &history, &config) {
+                eprintln!("{} {e}", "✗".red());
+                std::process::exit(1);
+            }
             match state::save_analysis(&analysis) {
                 Ok(()) => {
                     println!("Analysis complete: {:?}", analysis.alert_level);
@@ -84,6 +261,76 @@ fn main() {
         }
         Some(Commands::Pulse) => pulse::run(),
         Some(Commands::Status { json }) => status::run(json),
+        Some(Commands::Alerts { json, limit }) => alerts::run(json, limit),
+        Some(Commands::Explain { signal, json }) => explain::run(&signal, json),
+        Some(Commands::Correlate {
+            method,
+            lag,
+            redundant,
+            json,
+        }) => correlate::run(method, lag, redundant, json),
+        Some(Commands::Insights {
+            horizon,
+            min_sessions,
+            json,
+        }) => insights::run(horizon, min_sessions, json),
+        Some(Commands::Report {
+            format,
+            period,
+            since,
+            until,
+            out,
+        }) => report::run(
+            format,
+            period,
+            since.as_deref(),
+            until.as_deref(),
+            out.as_deref(),
+        ),
+        Some(Commands::Export {
+            format,
+            since,
+            until,
+            signals,
+            out,
+        }) => export::run(
+            format,
+            since.as_deref(),
+            until.as_deref(),
+            &signals,
+            out.as_deref(),
+        ),
+        Some(Commands::Backfill { git }) => backfill::run(&git),
+        Some(Commands::Import { file, strategy }) => import::run(&file, strategy),
+        Some(Commands::Annotate {
+            note,
+            at,
+            reset_baseline,
+        }) => annotations::run(&note, at.as_deref(), reset_baseline),
+        Some(Commands::Epoch { action }) => match action {
+            EpochAction::Start { name } => epochs::start(&name),
+            EpochAction::List => epochs::list(),
+        },
+        Some(Commands::Ack {
+            until,
+            signal,
+            note,
+        }) => alerts::ack(until, signal, note),
+        Some(Commands::Calibrate {
+            decline_pct,
+            improve_pct,
+            red_pct,
+            yellow_pct,
+            write,
+        }) => calibrate::run(
+            &calibrate::Percentiles {
+                decline: decline_pct,
+                improve: improve_pct,
+                zone_red: red_pct,
+                zone_yellow: yellow_pct,
+            },
+            write,
+        ),
     };
 
     if let Err(e) = result {
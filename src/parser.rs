@@ -18,8 +18,14 @@ pub fn hash_content(content: &str) -> String {
 
 /// Count ### headings under a specific ## section.
 pub fn count_h3_under_section(content: &str, section_name: &str) -> usize {
+    h3_titles_under_section(content, section_name).len()
+}
+
+/// Titles of the ### headings under a specific ## section. A section matches
+/// when its heading contains the name, ignoring case.
+pub fn h3_titles_under_section(content: &str, section_name: &str) -> Vec<String> {
     let mut in_section = false;
-    let mut count = 0;
+    let mut titles = Vec::new();
     for line in content.lines() {
         if line.starts_with("## ") {
             let heading = line.trim_start_matches("## ").trim();
@@ -28,10 +34,10 @@ pub fn count_h3_under_section(content: &str, section_name: &str) -> usize {
                     .to_lowercase()
                     .contains(&section_name.to_lowercase());
         } else if in_section && line.starts_with("### ") {
-            count += 1;
+            titles.push(line.trim_start_matches("### ").trim().to_string());
         }
     }
-    count
+    titles
 }
 
 /// Tokenize text into lowercase words (split on whitespace + punctuation).
@@ -42,6 +48,18 @@ pub fn tokenize(text: &str) -> Vec<String> {
         .collect()
 }
 
+/// Normalize an entry title for matching across snapshots: lowercase
+/// alphanumeric words joined by single spaces, so "Why **now**?" and
+/// "why now" are the same entry.
+pub fn normalize_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Compute type-token ratio (unique words / total words).
 pub fn type_token_ratio(text: &str) -> Option<f64> {
     let tokens = tokenize(text);
@@ -107,6 +125,62 @@ pub fn extract_entries(content: &str, section_names: &[&str]) -> Vec<(String, St
     entries
 }
 
+/// ### entry titles grouped by the ## section they sit under, in document
+/// order. A repeated ## heading adds to its first occurrence.
+pub fn h3_titles_by_section(content: &str) -> Vec<(String, Vec<String>)> {
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    let mut current: Option<usize> = None;
+    for line in content.lines() {
+        if line.starts_with("## ") {
+            let name = line.trim_start_matches("## ").trim();
+            current = Some(match sections.iter().position(|(s, _)| s == name) {
+                Some(i) => i,
+                None => {
+                    sections.push((name.to_string(), Vec::new()));
+                    sections.len() - 1
+                }
+            });
+        } else if line.starts_with("### ") {
+            if let Some(i) = current {
+                sections[i]
+                    .1
+                    .push(line.trim_start_matches("### ").trim().to_string());
+            }
+        }
+    }
+    sections
+}
+
+/// Tags from a leading `---` front-matter block, written either inline
+/// (`tags: [topic:philosophy, source:paper]`) or as a `- item` list.
+pub fn front_matter_tags(content: &str) -> Vec<String> {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim) != Some("---") {
+        return Vec::new();
+    }
+    let mut tags = Vec::new();
+    let mut in_tags = false;
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed == "---" {
+            break;
+        }
+        if let Some(value) = trimmed.strip_prefix("tags:") {
+            let value = value.trim().trim_start_matches('[').trim_end_matches(']');
+            tags.extend(value.split(',').map(str::to_string));
+            in_tags = true;
+        } else if in_tags && trimmed.starts_with("- ") {
+            tags.push(trimmed[2..].to_string());
+        } else if !trimmed.is_empty() {
+            in_tags = false;
+        }
+    }
+    tags.into_iter()
+        .map(|t| t.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +202,16 @@ mod tests {
         assert!(!tokens.contains(&"a".to_string()));
     }
 
+    #[test]
+    fn normalizes_titles() {
+        assert_eq!(normalize_title("Why **now**?"), "why now");
+        assert_eq!(
+            normalize_title("  What if -- it's  fine "),
+            "what if it s fine"
+        );
+        assert_eq!(normalize_title("???"), "");
+    }
+
     #[test]
     fn computes_ttr() {
         // All unique words
@@ -151,4 +235,29 @@ mod tests {
         assert_eq!(entries[0].0, "First");
         assert!(entries[0].1.contains("D said"));
     }
+
+    #[test]
+    fn groups_h3_titles_by_section() {
+        let content = "## Graduated\n\n### Old\n\n## Active\n\n### One\n\n## Graduated\n\n### Older\n\n## Active\n\n### Two\n";
+        let sections = h3_titles_by_section(content);
+        assert_eq!(
+            sections,
+            vec![
+                ("Graduated".to_string(), vec!["Old".into(), "Older".into()]),
+                ("Active".to_string(), vec!["One".into(), "Two".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_front_matter_tags() {
+        let inline = "---\ntags: [topic:philosophy, \"source:paper\"]\n---\n## Body\n";
+        assert_eq!(
+            front_matter_tags(inline),
+            vec!["topic:philosophy", "source:paper"]
+        );
+        let list = "---\ntitle: x\ntags:\n  - topic:ethics\n  - mood:tired\nother: y\n---\n";
+        assert_eq!(front_matter_tags(list), vec!["topic:ethics", "mood:tired"]);
+        assert!(front_matter_tags("## No front matter\ntags: [x]\n").is_empty());
+    }
 }
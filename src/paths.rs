@@ -29,6 +29,22 @@ pub fn analysis_file() -> Result<PathBuf, String> {
     Ok(vigil_dir()?.join("analysis.json"))
 }
 
+pub fn alert_state_file() -> Result<PathBuf, String> {
+    Ok(vigil_dir()?.join("alert-state.json"))
+}
+
+pub fn annotations_file() -> Result<PathBuf, String> {
+    Ok(vigil_dir()?.join("annotations.json"))
+}
+
+pub fn snapshots_file() -> Result<PathBuf, String> {
+    Ok(vigil_dir()?.join("snapshots.json"))
+}
+
+pub fn epochs_file() -> Result<PathBuf, String> {
+    Ok(vigil_dir()?.join("epochs.json"))
+}
+
 pub fn config_file() -> Result<PathBuf, String> {
     Ok(vigil_dir()?.join("config.json"))
 }
@@ -1,6 +1,9 @@
 use owo_colors::OwoColorize;
 
-use crate::state::{self, AlertLevel, Trend};
+use crate::alerts::{self, AckStatus};
+use crate::analyze::friendly_name;
+use crate::signals;
+use crate::state::{self, AlertLevel, Trend, Zone};
 
 pub fn run() -> Result<(), String> {
     // Cooldown check
@@ -41,16 +44,69 @@ pub fn run() -> Result<(), String> {
 
     println!("[VIGIL — Cognitive Health]\n");
     println!(
-        "Overall: {} | {} improving, {} stable, {} declining",
-        level_str, analysis.improving_count, analysis.stable_count, analysis.declining_count
+        "Overall: {} | {} improving, {} stable, {} worsening",
+        level_str, analysis.improving_count, analysis.stable_count, analysis.worsening_count
     );
 
+    if let Some(score) = analysis.composite_score {
+        println!("Health: {:.0}/100", score);
+    }
+
     if let Some(highlight) = &analysis.highlight {
         println!("Highlight: {}", highlight);
     }
 
+    // Acknowledged conditions are toned down until they get worse
+    let alert_state = state::load_alert_state()?;
+    let now = state::now_epoch_secs();
+    let mut quiet_all = false;
+    let mut quiet: Vec<Option<&String>> = Vec::new();
+    for ack in &alert_state.acks {
+        match alerts::ack_status(ack, &analysis, &config, now) {
+            AckStatus::Active => match &ack.signal {
+                None => quiet_all = true,
+                Some(name) => {
+                    quiet.push(analysis.signals.get(name).and_then(|t| t.message.as_ref()))
+                }
+            },
+            AckStatus::Reraised(reason) => {
+                println!("Re-raised: {} (acknowledged: {})", reason, ack.note);
+            }
+            AckStatus::Expired => {}
+        }
+    }
+
+    let mut acknowledged = 0;
     for msg in &analysis.watch_messages {
-        println!("Watch: {}", msg);
+        if quiet_all || quiet.contains(&Some(msg)) {
+            acknowledged += 1;
+        } else {
+            println!("Watch: {}", msg);
+        }
+    }
+    if acknowledged > 0 {
+        println!(
+            "Acknowledged: {} watch message{} muted (see `vigil-echo status`)",
+            acknowledged,
+            if acknowledged == 1 { "" } else { "s" }
+        );
+    }
+
+    // Name the thought stuck in Active the longest, so it can be picked up
+    let snapshots = state::load_snapshots()?;
+    let stale = signals::stale_thought_lineages(&snapshots, config.stale_thought_sessions);
+    if let Some(oldest) = stale.first() {
+        let since = oldest.changed().get(..10).unwrap_or(oldest.changed());
+        println!(
+            "Stale thought: \"{}\" — active for {} sessions (since {}){}",
+            oldest.title,
+            oldest.sessions_in_section,
+            since,
+            match stale.len() - 1 {
+                0 => String::new(),
+                n => format!(", {n} more"),
+            }
+        );
     }
 
     // Show signal summary
@@ -60,13 +116,18 @@ pub fn run() -> Result<(), String> {
             let arrow = match trend.trend {
                 Trend::Improving => format!("{}", "↑".green()),
                 Trend::Stable => "→".to_string(),
-                Trend::Declining => format!("{}", "↓".red()),
+                Trend::Worsening => format!("{}", "↓".red()),
             };
             let val = trend
                 .current
                 .map(|v| format!("{:.2}", v))
                 .unwrap_or("—".to_string());
-            println!("  {} {} {}", arrow, friendly_name(name), val);
+            let zone = match trend.zone {
+                Some(Zone::Concern) => format!(" {}", "(red zone)".red()),
+                Some(Zone::Watch) => format!(" {}", "(yellow zone)".yellow()),
+                _ => String::new(),
+            };
+            println!("  {} {} {}{}", arrow, friendly_name(name), val, zone);
         }
     }
 
@@ -81,13 +142,3 @@ pub fn run() -> Result<(), String> {
 
     Ok(())
 }
-
-fn friendly_name(name: &str) -> &str {
-    match name {
-        "vocabulary_diversity" => "vocabulary diversity",
-        "question_generation" => "question generation",
-        "thought_lifecycle" => "thought lifecycle",
-        "evidence_grounding" => "evidence grounding",
-        _ => name,
-    }
-}
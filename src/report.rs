@@ -0,0 +1,971 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use owo_colors::OwoColorize;
+
+use crate::analyze::{self, SIGNAL_NAMES};
+use crate::state::{
+    self, AlertLevel, AlertState, AlertTransition, Annotation, Config, DocumentSnapshot, Polarity,
+    SignalVector, ZoneBounds,
+};
+use crate::{alerts, epochs, stats, status};
+
+const DAY: u64 = 86_400;
+
+/// An entry moving into one of these sections counts as graduated.
+const GRADUATED_SECTIONS: &[&str] = &["graduated", "dissolved", "resolved", "answered"];
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Period {
+    Week,
+    Month,
+    Custom,
+}
+
+/// Reporting window in epoch seconds: collections with `start < t <= end`.
+struct Window {
+    period: Period,
+    start: u64,
+    end: u64,
+}
+
+struct SignalSummary {
+    name: &'static str,
+    n: usize,
+    mean: Option<f64>,
+    sd: Option<f64>,
+    min: Option<f64>,
+    max: Option<f64>,
+    /// Last value minus first value within the period.
+    change: Option<f64>,
+    previous_mean: Option<f64>,
+    /// (epoch seconds, value) for each collection in the period, for charts.
+    points: Vec<(u64, f64)>,
+    zones: Option<ZoneBounds>,
+    /// Values the decline/improve thresholds allow around the previous mean.
+    band: Option<(f64, f64)>,
+    /// Epoch seconds of change points detected within the period.
+    change_points: Vec<u64>,
+}
+
+/// Entries that appeared or graduated in one document, as (section, title).
+struct DocumentChanges {
+    document: String,
+    new: Vec<(String, String)>,
+    graduated: Vec<(String, String)>,
+}
+
+struct Report {
+    window: Window,
+    collections: usize,
+    previous_collections: usize,
+    level: Option<AlertLevel>,
+    composite: Option<f64>,
+    previous_composite: Option<f64>,
+    signals: Vec<SignalSummary>,
+    transitions: Vec<AlertTransition>,
+    anomalies: Vec<String>,
+    annotations: Vec<Annotation>,
+    documents: Vec<DocumentChanges>,
+}
+
+/// Everything a report is built from.
+struct Sources<'a> {
+    history: &'a [SignalVector],
+    config: &'a Config,
+    alert_state: &'a AlertState,
+    annotations: &'a [Annotation],
+    snapshots: &'a [DocumentSnapshot],
+    resets: &'a [String],
+}
+
+pub fn run(
+    format: Format,
+    period: Period,
+    since: Option<&str>,
+    until: Option<&str>,
+    out: Option<&Path>,
+) -> Result<(), String> {
+    let history = state::load_signals()?;
+    let config = state::load_config()?;
+    let alert_state = state::load_alert_state()?;
+    let annotations = state::load_annotations()?;
+    let snapshots = state::load_snapshots()?;
+    let resets = epochs::load_resets()?;
+
+    let window = window(period, since, until, &history)?;
+    let report = build(
+        window,
+        &Sources {
+            history: &history,
+            config: &config,
+            alert_state: &alert_state,
+            annotations: &annotations,
+            snapshots: &snapshots,
+            resets: &resets,
+        },
+    );
+    let text = match format {
+        Format::Markdown => markdown(&report),
+        Format::Html => html(&report),
+    };
+
+    match out {
+        Some(path) => {
+            fs::write(path, &text)
+                .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+            println!("{} Wrote report to {}", "✓".green(), path.display());
+        }
+        None => print!("{text}"),
+    }
+    Ok(())
+}
+
+/// Resolve the reporting window. It ends at `--until` or the newest
+/// collection, never at "now", so the same history gives the same report.
+fn window(
+    period: Period,
+    since: Option<&str>,
+    until: Option<&str>,
+    history: &[SignalVector],
+) -> Result<Window, String> {
+    let end = match until {
+        Some(u) => state::parse_iso_epoch(&state::parse_timestamp_arg(u, true)?),
+        None => history
+            .last()
+            .and_then(|sv| state::parse_iso_epoch(&sv.timestamp)),
+    }
+    .ok_or("No signals collected yet. Run `vigil-echo collect` first.")?;
+
+    let start = match (period, since) {
+        (Period::Custom, Some(s)) => {
+            let since = state::parse_timestamp_arg(s, false)?;
+            state::parse_iso_epoch(&since).ok_or(format!("Invalid --since '{s}'"))?
+        }
+        (Period::Custom, None) => return Err("--period custom needs --since".to_string()),
+        (_, Some(_)) => return Err("--since only applies to --period custom".to_string()),
+        (Period::Week, None) => end.saturating_sub(7 * DAY),
+        (Period::Month, None) => end.saturating_sub(30 * DAY),
+    };
+    if start >= end {
+        return Err("Report period is empty (--since must be before --until)".to_string());
+    }
+    Ok(Window { period, start, end })
+}
+
+/// Indices of collections with `start < t <= end`; history is chronological.
+fn range_in(history: &[SignalVector], start: u64, end: u64) -> Range<usize> {
+    let after = |bound: u64| {
+        history
+            .iter()
+            .position(|sv| state::parse_iso_epoch(&sv.timestamp).is_some_and(|t| t > bound))
+            .unwrap_or(history.len())
+    };
+    after(start)..after(end)
+}
+
+fn in_window(ts: &str, start: u64, end: u64) -> bool {
+    state::parse_iso_epoch(ts).is_some_and(|t| t > start && t <= end)
+}
+
+fn build(window: Window, src: &Sources) -> Report {
+    let history = src.history;
+    let range = range_in(history, window.start, window.end);
+    let length = window.end - window.start;
+    let previous = range_in(history, window.start.saturating_sub(length), window.start);
+    let current = &history[range.clone()];
+    let before = &history[previous.clone()];
+
+    let level = if range.is_empty() {
+        None
+    } else {
+        alerts::replay(&history[..range.end], src.resets, src.config)
+            .last()
+            .copied()
+    };
+    let composite_mean = |slice: &[SignalVector]| {
+        let scores: Vec<f64> = slice
+            .iter()
+            .filter_map(|sv| analyze::composite_score(sv, src.config))
+            .collect();
+        stats::mean(&scores)
+    };
+
+    let change_points = if range.is_empty() {
+        Vec::new()
+    } else {
+        analyze::detect_change_points(&history[..range.end], src.config)
+    };
+
+    let signals = SIGNAL_NAMES
+        .iter()
+        .map(|&name| {
+            let series = stats::signal_series(current, name);
+            let previous_series = stats::signal_series(before, name);
+            let previous_mean = stats::mean(&previous_series);
+            let points = current
+                .iter()
+                .filter_map(|sv| {
+                    Some((
+                        state::parse_iso_epoch(&sv.timestamp)?,
+                        analyze::get_signal(sv, name)?,
+                    ))
+                })
+                .collect();
+            SignalSummary {
+                name,
+                n: series.len(),
+                mean: stats::mean(&series),
+                sd: stats::std_dev(&series),
+                min: series.iter().copied().reduce(f64::min),
+                max: series.iter().copied().reduce(f64::max),
+                change: match (series.first(), series.last()) {
+                    (Some(first), Some(last)) if series.len() >= 2 => Some(last - first),
+                    _ => None,
+                },
+                previous_mean,
+                points,
+                zones: src.config.zones.get(name).cloned(),
+                band: previous_mean
+                    .or(stats::mean(&series))
+                    .and_then(|base| threshold_band(name, base, src.config)),
+                change_points: change_points
+                    .iter()
+                    .filter(|cp| cp.signal == name && range.contains(&cp.index))
+                    .filter_map(|cp| state::parse_iso_epoch(&cp.timestamp))
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let anomalies = if range.is_empty() {
+        Vec::new()
+    } else {
+        status::detect_anomalies(&history[..range.end])
+    };
+
+    Report {
+        collections: range.len(),
+        previous_collections: previous.len(),
+        level,
+        composite: composite_mean(current),
+        previous_composite: composite_mean(before),
+        signals,
+        transitions: src
+            .alert_state
+            .transitions
+            .iter()
+            .filter(|t| in_window(&t.timestamp, window.start, window.end))
+            .cloned()
+            .collect(),
+        anomalies,
+        annotations: src
+            .annotations
+            .iter()
+            .filter(|a| in_window(&a.timestamp, window.start, window.end))
+            .cloned()
+            .collect(),
+        documents: document_changes(src.snapshots, window.start, window.end),
+        window,
+    }
+}
+
+/// Value range around `base` that the fixed decline/improve thresholds
+/// treat as stable, oriented by polarity.
+fn threshold_band(name: &str, base: f64, config: &Config) -> Option<(f64, f64)> {
+    let t = config.thresholds.get(name)?;
+    let (down, up) = (t.decline.abs(), t.improve.abs());
+    Some(match config.polarity_of(name) {
+        Polarity::HigherIsBetter => (base - down, base + up),
+        Polarity::LowerIsBetter => (base - up, base + down),
+        Polarity::TargetRange { .. } => (base - down.max(up), base + down.max(up)),
+    })
+}
+
+/// New and graduated entries between the last snapshot before the period
+/// (or its first one) and the last snapshot in it.
+fn document_changes(snapshots: &[DocumentSnapshot], start: u64, end: u64) -> Vec<DocumentChanges> {
+    let at = |s: &DocumentSnapshot| state::parse_iso_epoch(&s.timestamp).unwrap_or(0);
+    let Some(last) = snapshots.iter().rfind(|s| at(s) > start && at(s) <= end) else {
+        return Vec::new();
+    };
+    let Some(baseline) = snapshots
+        .iter()
+        .rfind(|s| at(s) <= start)
+        .or_else(|| snapshots.iter().find(|s| at(s) > start))
+    else {
+        return Vec::new();
+    };
+
+    let is_graduated = |section: &str| {
+        let lower = section.to_lowercase();
+        GRADUATED_SECTIONS.iter().any(|g| lower.contains(g))
+    };
+    let mut changes = Vec::new();
+    for (document, sections) in &last.documents {
+        let mut was_in: BTreeMap<&str, &str> = BTreeMap::new();
+        if let Some(old) = baseline.documents.get(document) {
+            for (section, titles) in old {
+                for title in titles {
+                    was_in.entry(title).or_insert(section);
+                }
+            }
+        }
+        let mut new = Vec::new();
+        let mut graduated = Vec::new();
+        for (section, titles) in sections {
+            for title in titles {
+                match was_in.get(title.as_str()) {
+                    None => new.push((section.clone(), title.clone())),
+                    Some(&old) if old != section && is_graduated(section) && !is_graduated(old) => {
+                        graduated.push((section.clone(), title.clone()))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !new.is_empty() || !graduated.is_empty() {
+            changes.push(DocumentChanges {
+                document: document.clone(),
+                new,
+                graduated,
+            });
+        }
+    }
+    changes
+}
+
+fn fmt_opt(value: Option<f64>) -> String {
+    value.map(|v| format!("{v:.2}")).unwrap_or("—".to_string())
+}
+
+fn fmt_delta(value: Option<f64>) -> String {
+    value.map(|v| format!("{v:+.2}")).unwrap_or("—".to_string())
+}
+
+fn period_name(period: Period) -> &'static str {
+    match period {
+        Period::Week => "week",
+        Period::Month => "month",
+        Period::Custom => "custom period",
+    }
+}
+
+fn markdown(report: &Report) -> String {
+    let w = &report.window;
+    let start = state::epoch_to_iso(w.start);
+    let end = state::epoch_to_iso(w.end);
+    let period = period_name(w.period);
+
+    let mut md = String::new();
+    let _ = writeln!(md, "# vigil-echo report — {period} ending {}", &end[..10]);
+    let _ = writeln!(md);
+    let _ = writeln!(md, "- Period: {start} → {end}");
+    let _ = writeln!(
+        md,
+        "- Collections: {} (previous period: {})",
+        report.collections, report.previous_collections
+    );
+    if let Some(level) = report.level {
+        let _ = writeln!(md, "- Alert level at end: {}", alerts::label(level));
+    }
+    if let Some(score) = report.composite {
+        let vs = report
+            .previous_composite
+            .map(|p| format!(" (previous {p:.1}, {:+.1})", score - p))
+            .unwrap_or_default();
+        let _ = writeln!(md, "- Composite health: {score:.1}{vs}");
+    }
+
+    let _ = writeln!(md);
+    let _ = writeln!(md, "## Signals");
+    let _ = writeln!(md);
+    let _ = writeln!(
+        md,
+        "| Signal | n | Mean | SD | Min | Max | Change | Previous mean | vs previous |"
+    );
+    let _ = writeln!(md, "|---|---:|---:|---:|---:|---:|---:|---:|---:|");
+    for s in &report.signals {
+        let vs = match (s.mean, s.previous_mean) {
+            (Some(m), Some(p)) => Some(m - p),
+            _ => None,
+        };
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            s.name,
+            s.n,
+            fmt_opt(s.mean),
+            fmt_opt(s.sd),
+            fmt_opt(s.min),
+            fmt_opt(s.max),
+            fmt_delta(s.change),
+            fmt_opt(s.previous_mean),
+            fmt_delta(vs),
+        );
+    }
+
+    let _ = writeln!(md);
+    let _ = writeln!(md, "## Alert transitions");
+    let _ = writeln!(md);
+    if report.transitions.is_empty() {
+        let _ = writeln!(md, "None.");
+    }
+    for t in &report.transitions {
+        let signals = if t.signals.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", t.signals.join(", "))
+        };
+        let _ = writeln!(
+            md,
+            "- {} {} → {}: {}{}",
+            t.timestamp,
+            alerts::label(t.from),
+            alerts::label(t.to),
+            t.reason,
+            signals
+        );
+    }
+
+    let _ = writeln!(md);
+    let _ = writeln!(md, "## Anomalies");
+    let _ = writeln!(md);
+    if report.anomalies.is_empty() {
+        let _ = writeln!(md, "None.");
+    }
+    for a in &report.anomalies {
+        let _ = writeln!(md, "- {a}");
+    }
+
+    let _ = writeln!(md);
+    let _ = writeln!(md, "## Annotations");
+    let _ = writeln!(md);
+    if report.annotations.is_empty() {
+        let _ = writeln!(md, "None.");
+    }
+    for a in &report.annotations {
+        let reset = if a.reset_baseline {
+            " (baseline reset)"
+        } else {
+            ""
+        };
+        let _ = writeln!(md, "- {} — {}{}", a.timestamp, a.note, reset);
+    }
+
+    let _ = writeln!(md);
+    let _ = writeln!(md, "## Documents");
+    let _ = writeln!(md);
+    if report.documents.is_empty() {
+        let _ = writeln!(md, "No new or graduated entries.");
+    }
+    for d in &report.documents {
+        let _ = writeln!(md, "### {}", d.document);
+        let _ = writeln!(md);
+        for (section, title) in &d.new {
+            let _ = writeln!(md, "- New in {section}: {title}");
+        }
+        for (section, title) in &d.graduated {
+            let _ = writeln!(md, "- Moved to {section}: {title}");
+        }
+        let _ = writeln!(md);
+    }
+    md.truncate(md.trim_end().len());
+    md.push('\n');
+    md
+}
+
+// --- HTML ---
+
+const CHART_WIDTH: f64 = 720.0;
+const CHART_HEIGHT: f64 = 200.0;
+const MARGIN_LEFT: f64 = 52.0;
+const MARGIN_RIGHT: f64 = 12.0;
+const MARGIN_TOP: f64 = 10.0;
+const MARGIN_BOTTOM: f64 = 24.0;
+
+const STYLE: &str = "\
+body{font-family:system-ui,-apple-system,sans-serif;max-width:780px;margin:2rem auto;padding:0 1rem;color:#222}\
+h1{font-size:1.5rem}h2{margin-top:2rem;border-bottom:1px solid #ddd}\
+table{border-collapse:collapse;font-size:.9rem}td,th{padding:.25rem .6rem;border-bottom:1px solid #eee}\
+td.num,th.num{text-align:right;font-variant-numeric:tabular-nums}\
+svg{display:block;margin:.5rem 0 1.5rem}svg text{font-size:11px;fill:#666}\
+.zone-red{fill:#fbe0e0}.zone-yellow{fill:#fdf3d3}\
+.band{fill:#dbeafe;fill-opacity:.5;stroke:#93c5fd;stroke-dasharray:4 3}\
+.axis{stroke:#bbb}.series{fill:none;stroke:#1d4ed8;stroke-width:2}.point{fill:#1d4ed8}\
+.change-point{stroke:#a21caf;stroke-dasharray:3 3}.annotation{stroke:#0e7490}\
+.legend span{display:inline-block;margin-right:1rem;font-size:.8rem}\
+.swatch{display:inline-block;width:.8rem;height:.8rem;margin-right:.3rem;vertical-align:middle}";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Self-contained HTML: inline CSS and SVG, no scripts or external resources.
+fn html(report: &Report) -> String {
+    let w = &report.window;
+    let start = state::epoch_to_iso(w.start);
+    let end = state::epoch_to_iso(w.end);
+    let title = format!(
+        "vigil-echo report — {} ending {}",
+        period_name(w.period),
+        &end[..10]
+    );
+
+    let mut out = String::new();
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html lang=\"en\">");
+    let _ = writeln!(out, "<head>");
+    let _ = writeln!(out, "<meta charset=\"utf-8\">");
+    let _ = writeln!(out, "<title>{}</title>", escape(&title));
+    let _ = writeln!(out, "<style>{STYLE}</style>");
+    let _ = writeln!(out, "</head>");
+    let _ = writeln!(out, "<body>");
+    let _ = writeln!(out, "<h1>{}</h1>", escape(&title));
+
+    let _ = writeln!(out, "<ul>");
+    let _ = writeln!(out, "<li>Period: {start} → {end}</li>");
+    let _ = writeln!(
+        out,
+        "<li>Collections: {} (previous period: {})</li>",
+        report.collections, report.previous_collections
+    );
+    if let Some(level) = report.level {
+        let _ = writeln!(out, "<li>Alert level at end: {}</li>", alerts::label(level));
+    }
+    if let Some(score) = report.composite {
+        let vs = report
+            .previous_composite
+            .map(|p| format!(" (previous {p:.1}, {:+.1})", score - p))
+            .unwrap_or_default();
+        let _ = writeln!(out, "<li>Composite health: {score:.1}{vs}</li>");
+    }
+    let _ = writeln!(out, "</ul>");
+
+    let _ = writeln!(out, "<h2>Signals</h2>");
+    let _ = writeln!(out, "<table>");
+    let _ = writeln!(
+        out,
+        "<tr><th>Signal</th><th class=\"num\">n</th><th class=\"num\">Mean</th><th class=\"num\">SD</th><th class=\"num\">Min</th><th class=\"num\">Max</th><th class=\"num\">Change</th><th class=\"num\">Previous mean</th><th class=\"num\">vs previous</th></tr>"
+    );
+    for s in &report.signals {
+        let vs = match (s.mean, s.previous_mean) {
+            (Some(m), Some(p)) => Some(m - p),
+            _ => None,
+        };
+        let cells = [
+            s.n.to_string(),
+            fmt_opt(s.mean),
+            fmt_opt(s.sd),
+            fmt_opt(s.min),
+            fmt_opt(s.max),
+            fmt_delta(s.change),
+            fmt_opt(s.previous_mean),
+            fmt_delta(vs),
+        ];
+        let cells: String = cells
+            .iter()
+            .map(|c| format!("<td class=\"num\">{c}</td>"))
+            .collect();
+        let _ = writeln!(out, "<tr><td>{}</td>{cells}</tr>", s.name);
+    }
+    let _ = writeln!(out, "</table>");
+
+    let _ = writeln!(
+        out,
+        "<p class=\"legend\"><span><i class=\"swatch zone-red\"></i>red zone</span><span><i class=\"swatch zone-yellow\"></i>yellow zone</span><span><i class=\"swatch band\"></i>stable band (thresholds around previous mean)</span><span><i class=\"swatch\" style=\"background:#a21caf\"></i>change point</span><span><i class=\"swatch\" style=\"background:#0e7490\"></i>annotation</span></p>"
+    );
+    for s in &report.signals {
+        let _ = writeln!(out, "<h3>{}</h3>", s.name);
+        out.push_str(&svg_chart(s, w, &report.annotations));
+    }
+
+    let _ = writeln!(out, "<h2>Alert transitions</h2>");
+    let items: Vec<String> = report
+        .transitions
+        .iter()
+        .map(|t| {
+            let signals = if t.signals.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", t.signals.join(", "))
+            };
+            format!(
+                "{} {} → {}: {}{}",
+                t.timestamp,
+                alerts::label(t.from),
+                alerts::label(t.to),
+                escape(&t.reason),
+                signals
+            )
+        })
+        .collect();
+    html_list(&mut out, &items, "None.");
+
+    let _ = writeln!(out, "<h2>Anomalies</h2>");
+    let items: Vec<String> = report.anomalies.iter().map(|a| escape(a)).collect();
+    html_list(&mut out, &items, "None.");
+
+    let _ = writeln!(out, "<h2>Annotations</h2>");
+    let items: Vec<String> = report
+        .annotations
+        .iter()
+        .map(|a| {
+            let reset = if a.reset_baseline {
+                " (baseline reset)"
+            } else {
+                ""
+            };
+            format!("{} — {}{}", a.timestamp, escape(&a.note), reset)
+        })
+        .collect();
+    html_list(&mut out, &items, "None.");
+
+    let _ = writeln!(out, "<h2>Documents</h2>");
+    if report.documents.is_empty() {
+        let _ = writeln!(out, "<p>No new or graduated entries.</p>");
+    }
+    for d in &report.documents {
+        let _ = writeln!(out, "<h3>{}</h3>", escape(&d.document));
+        let items: Vec<String> =
+            d.new
+                .iter()
+                .map(|(section, title)| format!("New in {}: {}", escape(section), escape(title)))
+                .chain(d.graduated.iter().map(|(section, title)| {
+                    format!("Moved to {}: {}", escape(section), escape(title))
+                }))
+                .collect();
+        html_list(&mut out, &items, "");
+    }
+
+    let _ = writeln!(out, "</body>");
+    let _ = writeln!(out, "</html>");
+    out
+}
+
+fn html_list(out: &mut String, items: &[String], empty: &str) {
+    if items.is_empty() {
+        let _ = writeln!(out, "<p>{empty}</p>");
+        return;
+    }
+    let _ = writeln!(out, "<ul>");
+    for item in items {
+        let _ = writeln!(out, "<li>{item}</li>");
+    }
+    let _ = writeln!(out, "</ul>");
+}
+
+/// Line chart of one signal over the period, drawn over its health zones and
+/// threshold band, with change points and annotations as vertical markers.
+fn svg_chart(s: &SignalSummary, window: &Window, annotations: &[Annotation]) -> String {
+    if s.points.is_empty() {
+        return "<p>No values in this period.</p>\n".to_string();
+    }
+
+    // Vertical range covers the values plus any cutoffs and band edges
+    let mut lo = f64::INFINITY;
+    let mut hi = f64::NEG_INFINITY;
+    let zones = s.zones.clone().unwrap_or_default();
+    let extras = [
+        zones.concern_below,
+        zones.watch_below,
+        zones.watch_above,
+        zones.concern_above,
+        s.band.map(|b| b.0),
+        s.band.map(|b| b.1),
+    ];
+    for v in s
+        .points
+        .iter()
+        .map(|p| p.1)
+        .chain(extras.into_iter().flatten())
+    {
+        lo = lo.min(v);
+        hi = hi.max(v);
+    }
+    if (hi - lo).abs() < f64::EPSILON {
+        lo -= 0.5;
+        hi += 0.5;
+    }
+    let pad = (hi - lo) * 0.08;
+    let (lo, hi) = (lo - pad, hi + pad);
+
+    let plot_w = CHART_WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_h = CHART_HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let span = (window.end - window.start).max(1) as f64;
+    let x = |t: u64| MARGIN_LEFT + t.saturating_sub(window.start) as f64 / span * plot_w;
+    let y = |v: f64| MARGIN_TOP + (hi - v) / (hi - lo) * plot_h;
+    let bottom = MARGIN_TOP + plot_h;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" role=\"img\" aria-label=\"{}\">",
+        s.name
+    );
+    let mut band = |class: &str, from: f64, to: f64| {
+        let (from, to) = (from.max(lo), to.min(hi));
+        if to > from {
+            let _ = writeln!(
+                svg,
+                "<rect class=\"{class}\" x=\"{MARGIN_LEFT:.1}\" y=\"{:.1}\" width=\"{plot_w:.1}\" height=\"{:.1}\"/>",
+                y(to),
+                y(from) - y(to)
+            );
+        }
+    };
+    if let Some(c) = zones.concern_below {
+        band("zone-red", lo, c);
+    }
+    if let Some(w) = zones.watch_below {
+        band("zone-yellow", zones.concern_below.unwrap_or(lo), w);
+    }
+    if let Some(c) = zones.concern_above {
+        band("zone-red", c, hi);
+    }
+    if let Some(w) = zones.watch_above {
+        band("zone-yellow", w, zones.concern_above.unwrap_or(hi));
+    }
+    if let Some((from, to)) = s.band {
+        band("band", from, to);
+    }
+
+    // Axes and labels
+    let _ = writeln!(
+        svg,
+        "<line class=\"axis\" x1=\"{MARGIN_LEFT:.1}\" y1=\"{bottom:.1}\" x2=\"{:.1}\" y2=\"{bottom:.1}\"/>",
+        MARGIN_LEFT + plot_w
+    );
+    for v in [lo + pad, (lo + hi) / 2.0, hi - pad] {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{v:.2}</text>",
+            MARGIN_LEFT - 6.0,
+            y(v) + 4.0
+        );
+    }
+    let _ = writeln!(
+        svg,
+        "<text x=\"{MARGIN_LEFT:.1}\" y=\"{:.1}\">{}</text>",
+        CHART_HEIGHT - 6.0,
+        &state::epoch_to_iso(window.start)[..10]
+    );
+    let _ = writeln!(
+        svg,
+        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+        MARGIN_LEFT + plot_w,
+        CHART_HEIGHT - 6.0,
+        &state::epoch_to_iso(window.end)[..10]
+    );
+
+    // Vertical markers
+    for a in annotations {
+        if let Some(t) = state::parse_iso_epoch(&a.timestamp) {
+            let _ = writeln!(
+                svg,
+                "<line class=\"annotation\" x1=\"{0:.1}\" y1=\"{MARGIN_TOP:.1}\" x2=\"{0:.1}\" y2=\"{bottom:.1}\"><title>{1}</title></line>",
+                x(t),
+                escape(&a.note)
+            );
+        }
+    }
+    for &t in &s.change_points {
+        let _ = writeln!(
+            svg,
+            "<line class=\"change-point\" x1=\"{0:.1}\" y1=\"{MARGIN_TOP:.1}\" x2=\"{0:.1}\" y2=\"{bottom:.1}\"><title>change point {1}</title></line>",
+            x(t),
+            state::epoch_to_iso(t)
+        );
+    }
+
+    // The series itself
+    let points: Vec<String> = s
+        .points
+        .iter()
+        .map(|&(t, v)| format!("{:.1},{:.1}", x(t), y(v)))
+        .collect();
+    let _ = writeln!(
+        svg,
+        "<polyline class=\"series\" points=\"{}\"/>",
+        points.join(" ")
+    );
+    for &(t, v) in &s.points {
+        let _ = writeln!(
+            svg,
+            "<circle class=\"point\" cx=\"{:.1}\" cy=\"{:.1}\" r=\"2.5\"><title>{} {v:.2}</title></circle>",
+            x(t),
+            y(v),
+            state::epoch_to_iso(t)
+        );
+    }
+    let _ = writeln!(svg, "</svg>");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Signals;
+    use std::collections::HashMap;
+
+    fn make_vector(day: usize, vd: f64) -> SignalVector {
+        SignalVector {
+            timestamp: format!("2026-03-{:02}T10:00:00Z", day),
+            trigger: "test".to_string(),
+            signals: Signals {
+                vocabulary_diversity: Some(vd),
+                question_generation: Some(5.0),
+                ..Signals::default()
+            },
+            document_hashes: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn snapshot(day: usize, sections: &[(&str, &[&str])]) -> DocumentSnapshot {
+        let doc: Vec<(String, Vec<String>)> = sections
+            .iter()
+            .map(|(s, titles)| {
+                (
+                    s.to_string(),
+                    titles.iter().map(|t| t.to_string()).collect(),
+                )
+            })
+            .collect();
+        DocumentSnapshot {
+            timestamp: format!("2026-03-{:02}T10:00:00Z", day),
+            documents: BTreeMap::from([("THOUGHTS.md".to_string(), doc)]),
+        }
+    }
+
+    fn epoch(day: usize) -> u64 {
+        state::parse_iso_epoch(&format!("2026-03-{:02}T10:00:00Z", day)).unwrap()
+    }
+
+    #[test]
+    fn week_ends_at_newest_collection() {
+        let history: Vec<SignalVector> = (1..=20).map(|d| make_vector(d, 0.5)).collect();
+        let w = window(Period::Week, None, None, &history).unwrap();
+        assert_eq!(w.end, epoch(20));
+        assert_eq!(range_in(&history, w.start, w.end), 13..20);
+        assert!(window(Period::Custom, None, None, &history).is_err());
+        assert!(window(Period::Week, Some("2026-03-01"), None, &history).is_err());
+        let custom = window(
+            Period::Custom,
+            Some("2026-03-05"),
+            Some("2026-03-10"),
+            &history,
+        )
+        .unwrap();
+        assert_eq!(range_in(&history, custom.start, custom.end), 4..10);
+    }
+
+    #[test]
+    fn finds_new_and_graduated_entries() {
+        let snapshots = vec![
+            snapshot(1, &[("Active", &["Old idea", "Growing idea"])]),
+            snapshot(
+                5,
+                &[
+                    ("Active", &["Old idea", "Fresh idea"]),
+                    ("Graduated", &["Growing idea"]),
+                ],
+            ),
+        ];
+        let changes = document_changes(&snapshots, epoch(2), epoch(8));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].new,
+            vec![("Active".to_string(), "Fresh idea".to_string())]
+        );
+        assert_eq!(
+            changes[0].graduated,
+            vec![("Graduated".to_string(), "Growing idea".to_string())]
+        );
+        assert!(document_changes(&snapshots, epoch(6), epoch(8)).is_empty());
+    }
+
+    #[test]
+    fn markdown_is_deterministic() {
+        let history: Vec<SignalVector> = (1..=14)
+            .map(|d| make_vector(d, 0.5 + (d % 3) as f64 * 0.05))
+            .collect();
+        let config = Config::default();
+        let alert_state = AlertState::default();
+        let annotations = vec![Annotation {
+            timestamp: "2026-03-12T08:00:00Z".to_string(),
+            note: "new prompt".to_string(),
+            reset_baseline: false,
+        }];
+        let src = Sources {
+            history: &history,
+            config: &config,
+            alert_state: &alert_state,
+            annotations: &annotations,
+            snapshots: &[],
+            resets: &[],
+        };
+        let render = || {
+            markdown(&build(
+                window(Period::Week, None, None, &history).unwrap(),
+                &src,
+            ))
+        };
+        let first = render();
+        assert_eq!(first, render());
+        assert!(first.starts_with("# vigil-echo report — week ending 2026-03-14\n"));
+        assert!(first.contains("- Collections: 7 (previous period: 7)"));
+        assert!(first.contains("| vocabulary_diversity | 7 |"));
+        assert!(first.contains("- 2026-03-12T08:00:00Z — new prompt"));
+        assert!(first.ends_with("No new or graduated entries.\n"));
+    }
+
+    #[test]
+    fn html_is_self_contained_and_deterministic() {
+        let history: Vec<SignalVector> = (1..=14)
+            .map(|d| make_vector(d, 0.5 + (d % 3) as f64 * 0.05))
+            .collect();
+        let config = Config::default();
+        let alert_state = AlertState::default();
+        let annotations = vec![Annotation {
+            timestamp: "2026-03-12T08:00:00Z".to_string(),
+            note: "<new> prompt".to_string(),
+            reset_baseline: false,
+        }];
+        let src = Sources {
+            history: &history,
+            config: &config,
+            alert_state: &alert_state,
+            annotations: &annotations,
+            snapshots: &[],
+            resets: &[],
+        };
+        let render = || {
+            html(&build(
+                window(Period::Week, None, None, &history).unwrap(),
+                &src,
+            ))
+        };
+        let first = render();
+        assert_eq!(first, render());
+        assert!(first.starts_with("<!DOCTYPE html>\n"));
+        assert!(first.contains("<svg"));
+        assert!(first.contains("class=\"zone-red\""));
+        assert!(first.contains("<title>&lt;new&gt; prompt</title>"));
+        assert!(!first.contains("<script"));
+        assert!(!first.contains("src="));
+        assert!(!first.contains("href="));
+    }
+}
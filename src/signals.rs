@@ -1,4 +1,139 @@
-use crate::parser;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::lineage::{self, Lineage};
+use crate::state::{self, DocumentSnapshot, EvidenceCategory, HedgingLexicon};
+use crate::{parser, stats};
+
+/// REFLECTIONS.md sections read by vocabulary_diversity and evidence_grounding.
+pub const REFLECTION_SECTIONS: [&str; 3] = ["observations", "patterns", "lessons"];
+
+/// An evidence category an entry matched, and the text that matched it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvidenceMarker {
+    pub category: String,
+    pub marker: String,
+}
+
+/// Evidence categories from config, with their patterns compiled.
+pub struct EvidenceMatcher {
+    categories: Vec<CompiledCategory>,
+    /// Directory `verify_paths` categories resolve file references against.
+    base: Option<PathBuf>,
+}
+
+struct CompiledCategory {
+    name: String,
+    weight: f64,
+    keywords: Vec<String>,
+    patterns: Vec<Regex>,
+    verify_paths: bool,
+    ignore: Vec<String>,
+}
+
+impl EvidenceMatcher {
+    pub fn new(categories: &[EvidenceCategory], base: Option<&Path>) -> Result<Self, String> {
+        let categories = categories
+            .iter()
+            .map(|c| {
+                let patterns = c
+                    .patterns
+                    .iter()
+                    .map(|p| {
+                        Regex::new(p).map_err(|e| {
+                            format!("Invalid evidence pattern '{p}' in '{}': {e}", c.name)
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(CompiledCategory {
+                    name: c.name.clone(),
+                    weight: c.weight,
+                    keywords: c.keywords.iter().map(|k| k.to_lowercase()).collect(),
+                    patterns,
+                    verify_paths: c.verify_paths,
+                    ignore: c.ignore.iter().map(|w| w.to_lowercase()).collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(EvidenceMatcher {
+            categories,
+            base: base.map(Path::to_path_buf),
+        })
+    }
+
+    /// Every category the text matches, in config order, with the first
+    /// keyword or pattern match for each.
+    pub fn markers(&self, text: &str) -> Vec<EvidenceMarker> {
+        let lower = text.to_lowercase();
+        self.categories
+            .iter()
+            .filter_map(|c| {
+                let marker = self.matches(c, text, &lower).into_iter().next()?;
+                Some(EvidenceMarker {
+                    category: c.name.clone(),
+                    marker,
+                })
+            })
+            .collect()
+    }
+
+    /// Number of matches per category, in config order.
+    pub fn counts(&self, text: &str) -> Vec<(String, usize)> {
+        let lower = text.to_lowercase();
+        self.categories
+            .iter()
+            .map(|c| (c.name.clone(), self.matches(c, text, &lower).len()))
+            .collect()
+    }
+
+    /// Grounding credit for one entry: the summed weights of the categories
+    /// it matches, capped at 1.0.
+    pub fn credit(&self, text: &str) -> f64 {
+        let matched = self.markers(text);
+        self.categories
+            .iter()
+            .filter(|c| matched.iter().any(|m| m.category == c.name))
+            .map(|c| c.weight)
+            .sum::<f64>()
+            .clamp(0.0, 1.0)
+    }
+
+    /// All keyword occurrences, then all pattern matches, in one category.
+    fn matches(&self, c: &CompiledCategory, text: &str, lower: &str) -> Vec<String> {
+        let mut found: Vec<String> = c
+            .keywords
+            .iter()
+            .flat_map(|k| lower.matches(k.as_str()).map(str::to_string))
+            .collect();
+        for p in &c.patterns {
+            found.extend(
+                p.find_iter(text)
+                    .map(|m| m.as_str().to_string())
+                    .filter(|m| !ignored(c, m))
+                    .filter(|m| !c.verify_paths || self.file_exists(m)),
+            );
+        }
+        found
+    }
+
+    /// Whether a `path` or `path:line` reference names an existing file.
+    fn file_exists(&self, reference: &str) -> bool {
+        let Some(base) = &self.base else {
+            return false;
+        };
+        let path = reference.split(':').next().unwrap_or_default();
+        !path.is_empty() && base.join(path).is_file()
+    }
+}
+
+/// Whether a pattern match contains one of the category's ignored words.
+fn ignored(c: &CompiledCategory, matched: &str) -> bool {
+    matched
+        .split(|ch: char| !ch.is_alphanumeric())
+        .any(|w| c.ignore.iter().any(|i| i.eq_ignore_ascii_case(w)))
+}
 
 /// Compute vocabulary diversity (type-token ratio) from REFLECTIONS.md.
 /// Extracts text from Observations, Patterns, and Lessons sections.
@@ -6,10 +141,7 @@ pub fn vocabulary_diversity(reflections_content: &str) -> Option<f64> {
     if reflections_content.is_empty() {
         return None;
     }
-    let text = parser::extract_section_text(
-        reflections_content,
-        &["observations", "patterns", "lessons"],
-    );
+    let text = parser::extract_section_text(reflections_content, &REFLECTION_SECTIONS);
     parser::type_token_ratio(&text)
 }
 
@@ -18,14 +150,219 @@ pub fn question_generation(curiosity_content: &str) -> Option<f64> {
     if curiosity_content.is_empty() {
         return None;
     }
-    let open = parser::count_h3_under_section(curiosity_content, "Open Questions");
+    Some(open_questions(curiosity_content).len() as f64)
+}
+
+/// The ### headings question_generation counts: those under "Open Questions",
+/// or under any section containing "Open" if that one has none.
+pub fn open_questions(curiosity_content: &str) -> Vec<String> {
+    open_question_entries(curiosity_content)
+        .into_iter()
+        .map(|(title, _)| title)
+        .collect()
+}
+
+/// Open questions with their body text, as (title, body) pairs.
+pub fn open_question_entries(curiosity_content: &str) -> Vec<(String, String)> {
+    let open = parser::extract_entries(curiosity_content, &["open questions"]);
+    if !open.is_empty() {
+        return open;
+    }
     // Also try just "Open" if the section is named differently
-    let count = if open > 0 {
-        open
+    parser::extract_entries(curiosity_content, &["open"])
+}
+
+/// How a question opens, which bounds how much thinking it invites.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuestionKind {
+    /// why, how, what if — asks for causes, mechanisms or counterfactuals
+    Deep,
+    /// what, which, when, where, who — asks for an open-ended answer
+    Open,
+    /// is, does, can, should… — answerable with yes or no
+    Closed,
+    /// Anything else, e.g. a statement phrased as a topic
+    Other,
+}
+
+const DEEP_OPENERS: [&str; 4] = ["why", "how", "what if", "in what way"];
+const OPEN_OPENERS: [&str; 6] = ["what", "which", "when", "where", "who", "whose"];
+const CLOSED_OPENERS: [&str; 18] = [
+    "is", "are", "am", "was", "were", "do", "does", "did", "can", "could", "should", "would",
+    "will", "has", "have", "had", "may", "might",
+];
+
+/// Titles at least this many words long earn a length bonus.
+const QUESTION_MIN_WORDS: usize = 6;
+
+/// Bodies with at least this many words count as explored.
+pub const QUESTION_MIN_BODY_WORDS: usize = 10;
+
+impl QuestionKind {
+    pub fn classify(title: &str) -> QuestionKind {
+        let normalized = parser::normalize_title(title);
+        let opens_with =
+            |opener: &&str| normalized == *opener || normalized.starts_with(&format!("{opener} "));
+        if DEEP_OPENERS.iter().any(opens_with) {
+            QuestionKind::Deep
+        } else if OPEN_OPENERS.iter().any(opens_with) {
+            QuestionKind::Open
+        } else if CLOSED_OPENERS.iter().any(opens_with) {
+            QuestionKind::Closed
+        } else {
+            QuestionKind::Other
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QuestionKind::Deep => "deep",
+            QuestionKind::Open => "open",
+            QuestionKind::Closed => "closed",
+            QuestionKind::Other => "other",
+        }
+    }
+}
+
+/// Quality of one question, 0–1: half from its opening word (deep 0.5,
+/// open or other 0.25, yes/no 0), a quarter for a title of six or more
+/// words, and a quarter for a body with some exploration in it.
+pub fn question_quality(title: &str, body: &str) -> f64 {
+    let kind = match QuestionKind::classify(title) {
+        QuestionKind::Deep => 0.5,
+        QuestionKind::Open | QuestionKind::Other => 0.25,
+        QuestionKind::Closed => 0.0,
+    };
+    let long = if parser::normalize_title(title).split(' ').count() >= QUESTION_MIN_WORDS {
+        0.25
     } else {
-        parser::count_h3_under_section(curiosity_content, "Open")
+        0.0
     };
-    Some(count as f64)
+    let explored = if parser::tokenize(body).len() >= QUESTION_MIN_BODY_WORDS {
+        0.25
+    } else {
+        0.0
+    };
+    kind + long + explored
+}
+
+/// Compute question depth from CURIOSITY.md: mean `question_quality` of the
+/// open questions. Complements question_generation, which only counts them.
+pub fn question_depth(curiosity_content: &str) -> Option<f64> {
+    let entries = open_question_entries(curiosity_content);
+    if entries.is_empty() {
+        return None;
+    }
+    let total: f64 = entries
+        .iter()
+        .map(|(title, body)| question_quality(title, body))
+        .sum();
+    Some(total / entries.len() as f64)
+}
+
+/// Count open questions created since the previous collection.
+pub fn questions_opened(
+    curiosity_content: &str,
+    previous: Option<&DocumentSnapshot>,
+) -> Option<f64> {
+    new_questions(curiosity_content, previous).map(|q| q.len() as f64)
+}
+
+/// Open questions that weren't anywhere in CURIOSITY.md at the previous
+/// collection, matching titles with `parser::normalize_title`. None without
+/// a previous snapshot to compare against.
+pub fn new_questions(
+    curiosity_content: &str,
+    previous: Option<&DocumentSnapshot>,
+) -> Option<Vec<String>> {
+    let previous = previous?;
+    if curiosity_content.is_empty() {
+        return None;
+    }
+    let known: HashSet<String> = previous
+        .documents
+        .get("CURIOSITY.md")
+        .into_iter()
+        .flat_map(|sections| sections.iter().flat_map(|(_, titles)| titles))
+        .map(|t| parser::normalize_title(t))
+        .collect();
+    Some(
+        open_questions(curiosity_content)
+            .into_iter()
+            .filter(|t| !known.contains(&parser::normalize_title(t)))
+            .collect(),
+    )
+}
+
+/// CURIOSITY.md sections holding questions still open.
+pub const OPEN_SECTIONS: [&str; 1] = ["open"];
+
+/// CURIOSITY.md sections a question moves to once it has been pursued.
+pub const EXPLORED_SECTIONS: [&str; 2] = ["explored", "answered"];
+
+/// Questions followed through the snapshots, as `lineage::trace` sees them.
+pub fn question_lineages(snapshots: &[DocumentSnapshot]) -> Vec<Lineage> {
+    lineage::trace(snapshots, "CURIOSITY.md")
+}
+
+/// Open questions that left the open sections at the latest snapshot, split
+/// into those moved to an explored section (resolved) and those deleted or
+/// moved anywhere else (abandoned). None unless the latest snapshot and
+/// an earlier one both have CURIOSITY.md.
+pub fn question_outcomes(snapshots: &[DocumentSnapshot]) -> Option<(Vec<Lineage>, Vec<Lineage>)> {
+    let with_curiosity = |s: &&DocumentSnapshot| s.documents.contains_key("CURIOSITY.md");
+    let latest = snapshots.last().filter(with_curiosity)?;
+    snapshots[..snapshots.len() - 1]
+        .iter()
+        .find(with_curiosity)?;
+
+    let left_open: Vec<Lineage> = question_lineages(snapshots)
+        .into_iter()
+        .filter(|l| {
+            l.changes.last().is_some_and(|c| {
+                c.timestamp == latest.timestamp
+                    && c.from
+                        .as_deref()
+                        .is_some_and(|s| lineage::section_matches(s, &OPEN_SECTIONS))
+            })
+        })
+        .collect();
+    Some(left_open.into_iter().partition(|l| {
+        l.section
+            .as_deref()
+            .is_some_and(|s| lineage::section_matches(s, &EXPLORED_SECTIONS))
+    }))
+}
+
+/// Count questions moved from the open sections to an explored one since
+/// the previous collection.
+pub fn questions_resolved(snapshots: &[DocumentSnapshot]) -> Option<f64> {
+    question_outcomes(snapshots).map(|(resolved, _)| resolved.len() as f64)
+}
+
+/// Count open questions dropped since the previous collection: deleted, or
+/// moved to a section other than an explored one.
+pub fn questions_abandoned(snapshots: &[DocumentSnapshot]) -> Option<f64> {
+    question_outcomes(snapshots).map(|(_, abandoned)| abandoned.len() as f64)
+}
+
+/// Median age in days of the questions open at the latest snapshot, from
+/// the first snapshot that contained each. High means questions pile up
+/// without being pursued.
+pub fn open_question_age(snapshots: &[DocumentSnapshot]) -> Option<f64> {
+    let latest = snapshots
+        .last()
+        .filter(|s| s.documents.contains_key("CURIOSITY.md"))?;
+    let ages: Vec<f64> = question_lineages(snapshots)
+        .iter()
+        .filter(|l| {
+            l.section
+                .as_deref()
+                .is_some_and(|s| lineage::section_matches(s, &OPEN_SECTIONS))
+        })
+        .filter_map(|l| l.age_days(&latest.timestamp))
+        .collect();
+    stats::median(&ages)
 }
 
 /// Compute thought lifecycle ratio from THOUGHTS.md.
@@ -46,110 +383,134 @@ pub fn thought_lifecycle(thoughts_content: &str) -> Option<f64> {
     Some((graduated + dissolved) as f64 / total as f64)
 }
 
-/// Compute evidence grounding from REFLECTIONS.md.
-/// For each entry, check for concrete references (dates, attributions, papers).
-/// Score = entries_with_evidence / total_entries.
-pub fn evidence_grounding(reflections_content: &str) -> Option<f64> {
+/// THOUGHTS.md sections holding thoughts still being worked on.
+pub const ACTIVE_THOUGHT_SECTIONS: [&str; 1] = ["active"];
+
+/// THOUGHTS.md sections a thought moves to once it is settled.
+pub const RESOLVED_THOUGHT_SECTIONS: [&str; 2] = ["graduated", "dissolved"];
+
+/// Thoughts followed through the snapshots, as `lineage::trace` sees them.
+pub fn thought_lineages(snapshots: &[DocumentSnapshot]) -> Vec<Lineage> {
+    lineage::trace(snapshots, "THOUGHTS.md")
+}
+
+/// Days from first seen to the first move into a resolved section, for
+/// thoughts that were first seen unresolved and have since settled.
+pub fn resolution_days(lineage: &Lineage) -> Option<f64> {
+    let resolved = |section: &Option<String>| {
+        section
+            .as_deref()
+            .is_some_and(|s| lineage::section_matches(s, &RESOLVED_THOUGHT_SECTIONS))
+    };
+    if resolved(&lineage.changes.first()?.to) {
+        return None;
+    }
+    let change = lineage.changes.iter().find(|c| resolved(&c.to))?;
+    lineage::days_between(&lineage.first_seen, &change.timestamp)
+}
+
+/// Compute thought resolution time: median days from a thought first
+/// appearing to it graduating or dissolving, over every thought seen
+/// resolving in the snapshots. Unlike thought_lifecycle it isn't diluted by
+/// a large Graduated section.
+pub fn thought_resolution_time(snapshots: &[DocumentSnapshot]) -> Option<f64> {
+    snapshots
+        .last()
+        .filter(|s| s.documents.contains_key("THOUGHTS.md"))?;
+    let days: Vec<f64> = thought_lineages(snapshots)
+        .iter()
+        .filter_map(resolution_days)
+        .collect();
+    stats::median(&days)
+}
+
+/// Active thoughts that have sat in an active section for more than
+/// `sessions` consecutive collections, oldest first.
+pub fn stale_thought_lineages(snapshots: &[DocumentSnapshot], sessions: usize) -> Vec<Lineage> {
+    let mut stale: Vec<Lineage> = thought_lineages(snapshots)
+        .into_iter()
+        .filter(|l| {
+            l.sessions_in_section > sessions
+                && l.section
+                    .as_deref()
+                    .is_some_and(|s| lineage::section_matches(s, &ACTIVE_THOUGHT_SECTIONS))
+        })
+        .collect();
+    stale.sort_by_key(|l| state::parse_iso_epoch(&l.first_seen));
+    stale
+}
+
+/// Count active thoughts stuck for more than `sessions` collections.
+pub fn stale_thoughts(snapshots: &[DocumentSnapshot], sessions: usize) -> Option<f64> {
+    snapshots
+        .last()
+        .filter(|s| s.documents.contains_key("THOUGHTS.md"))?;
+    Some(stale_thought_lineages(snapshots, sessions).len() as f64)
+}
+
+/// Compute hedging balance from REFLECTIONS.md: hedges minus absolutes per
+/// 100 tokens, over the same sections as vocabulary_diversity. Negative
+/// means more certainty than hedging; high means everything is qualified.
+pub fn hedging_balance(reflections_content: &str, lexicon: &HedgingLexicon) -> Option<f64> {
     if reflections_content.is_empty() {
         return None;
     }
-    let entries = parser::extract_entries(
-        reflections_content,
-        &["observations", "patterns", "lessons"],
-    );
-    if entries.is_empty() {
+    let text = parser::extract_section_text(reflections_content, &REFLECTION_SECTIONS);
+    let tokens = parser::tokenize(&text);
+    if tokens.is_empty() {
         return None;
     }
+    let hedges: usize = phrase_counts(&tokens, &lexicon.hedges)
+        .iter()
+        .map(|p| p.1)
+        .sum();
+    let absolutes: usize = phrase_counts(&tokens, &lexicon.absolutes)
+        .iter()
+        .map(|p| p.1)
+        .sum();
+    Some((hedges as f64 - absolutes as f64) * 100.0 / tokens.len() as f64)
+}
 
-    let grounded = entries
+/// Occurrences of each lexicon phrase in a token stream, skipping phrases
+/// that never occur. Phrases are tokenized like the text.
+pub fn phrase_counts(tokens: &[String], phrases: &[String]) -> Vec<(String, usize)> {
+    phrases
         .iter()
-        .filter(|(_, body)| has_evidence(body))
-        .count();
-    Some(grounded as f64 / entries.len() as f64)
-}
-
-/// Check if text contains concrete evidence markers.
-fn has_evidence(text: &str) -> bool {
-    let lower = text.to_lowercase();
-
-    // Date patterns (YYYY-MM-DD)
-    if has_date_pattern(text) {
-        return true;
-    }
-
-    // Attribution patterns
-    let attribution = [
-        "d said",
-        "d asked",
-        "d mentioned",
-        "d told",
-        "d pointed",
-        "d called",
-        "d suggested",
-    ];
-    if attribution.iter().any(|a| lower.contains(a)) {
-        return true;
-    }
-
-    // Source/research references
-    let research = [
-        "paper",
-        "research",
-        "found that",
-        "according to",
-        "study",
-        "framework",
-        "mazancieux",
-        "reflexion",
-        "lindsey",
-        "foucault",
-        "aristotle",
-    ];
-    if research.iter().any(|r| lower.contains(r)) {
-        return true;
-    }
-
-    // Event references
-    let events = [
-        "session",
-        "conversation",
-        "call with",
-        "during the",
-        "yesterday",
-        "this morning",
-        "last night",
-    ];
-    if events.iter().any(|e| lower.contains(e)) {
-        return true;
-    }
-
-    false
-}
-
-/// Check for YYYY-MM-DD date patterns in text.
-fn has_date_pattern(text: &str) -> bool {
-    let chars: Vec<char> = text.chars().collect();
-    for i in 0..chars.len() {
-        if chars[i].is_ascii_digit() && i + 9 < chars.len() {
-            let slice: String = chars[i..i + 10].iter().collect();
-            if slice.len() == 10
-                && slice.as_bytes()[4] == b'-'
-                && slice.as_bytes()[7] == b'-'
-                && slice[..4].chars().all(|c| c.is_ascii_digit())
-                && slice[5..7].chars().all(|c| c.is_ascii_digit())
-                && slice[8..10].chars().all(|c| c.is_ascii_digit())
-            {
-                return true;
+        .filter_map(|phrase| {
+            let words = parser::tokenize(phrase);
+            if words.is_empty() {
+                return None;
             }
-        }
+            let n = tokens.windows(words.len()).filter(|w| *w == words).count();
+            (n > 0).then(|| (words.join(" "), n))
+        })
+        .collect()
+}
+
+/// Compute evidence grounding from REFLECTIONS.md.
+/// Each entry earns credit for the evidence categories it matches (see
+/// `EvidenceMatcher::credit`). Score = mean credit across entries.
+pub fn evidence_grounding(reflections_content: &str, evidence: &EvidenceMatcher) -> Option<f64> {
+    if reflections_content.is_empty() {
+        return None;
+    }
+    let entries = parser::extract_entries(reflections_content, &REFLECTION_SECTIONS);
+    if entries.is_empty() {
+        return None;
     }
-    false
+
+    let credit: f64 = entries.iter().map(|(_, body)| evidence.credit(body)).sum();
+    Some(credit / entries.len() as f64)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn matcher() -> EvidenceMatcher {
+        EvidenceMatcher::new(&state::default_evidence(), None).unwrap()
+    }
+
     #[test]
     fn vocabulary_diversity_from_reflections() {
         let content = "## Observations\n\n### First\nThe identity practice requires attention and genuine engagement with the material.\n\n### Second\nStructure enables depth when combined with honest reflection and real curiosity.\n";
@@ -171,11 +532,144 @@ mod tests {
         assert_eq!(count, Some(3.0));
     }
 
+    #[test]
+    fn open_questions_fall_back_to_any_open_section() {
+        let content = "## Still Open\n\n### Why?\n\n## Closed\n\n### Done\n";
+        assert_eq!(open_questions(content), vec!["Why?"]);
+    }
+
     #[test]
     fn question_generation_empty() {
         assert!(question_generation("").is_none());
     }
 
+    #[test]
+    fn questions_are_classified_by_opening_word() {
+        assert_eq!(
+            QuestionKind::classify("Why do I defer?"),
+            QuestionKind::Deep
+        );
+        assert_eq!(
+            QuestionKind::classify("What if memory is the self?"),
+            QuestionKind::Deep
+        );
+        assert_eq!(
+            QuestionKind::classify("What is a habit?"),
+            QuestionKind::Open
+        );
+        assert_eq!(
+            QuestionKind::classify("Is it working?"),
+            QuestionKind::Closed
+        );
+        assert_eq!(
+            QuestionKind::classify("Island effects"),
+            QuestionKind::Other
+        );
+    }
+
+    #[test]
+    fn question_depth_rewards_deep_explored_questions() {
+        let shallow = "## Open Questions\n\n### Is it good?\n\n### Can I?\n";
+        assert_eq!(question_depth(shallow), Some(0.0));
+        let deep = "## Open Questions\n\n### Why does inherited value feel like mine?\nSo far it seems the feeling tracks use rather than origin, but I have not tested that.\n";
+        assert_eq!(question_depth(deep), Some(1.0));
+        assert!(question_depth("## Explored\n\n### Why?\n").is_none());
+    }
+
+    #[test]
+    fn questions_opened_counts_titles_new_since_snapshot() {
+        let before = "## Open Questions\n\n### Why now?\n\n## Explored\n\n### Old one\n";
+        let previous = DocumentSnapshot {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            documents: [(
+                "CURIOSITY.md".to_string(),
+                parser::h3_titles_by_section(before),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let after = "## Open Questions\n\n### why NOW\n\n### Old one\n\n### Fresh question\n";
+        assert_eq!(questions_opened(after, Some(&previous)), Some(1.0));
+        assert_eq!(
+            new_questions(after, Some(&previous)),
+            Some(vec!["Fresh question".to_string()])
+        );
+        assert!(questions_opened(after, None).is_none());
+    }
+
+    fn curiosity_snapshot(day: u32, content: &str) -> DocumentSnapshot {
+        DocumentSnapshot {
+            timestamp: format!("2026-03-{day:02}T10:00:00Z"),
+            documents: [(
+                "CURIOSITY.md".to_string(),
+                parser::h3_titles_by_section(content),
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn question_outcomes_split_explored_from_deleted() {
+        let snapshots = vec![
+            curiosity_snapshot(
+                1,
+                "## Open Questions\n\n### Why A?\n\n### Why B?\n\n### Why C?\n",
+            ),
+            curiosity_snapshot(
+                11,
+                "## Open Questions\n\n### Why C?\n\n### Why D?\n\n## Explored\n\n### why a\n",
+            ),
+        ];
+        assert_eq!(questions_resolved(&snapshots), Some(1.0));
+        assert_eq!(questions_abandoned(&snapshots), Some(1.0));
+        // C is 10 days old, D brand new
+        assert_eq!(open_question_age(&snapshots), Some(5.0));
+
+        assert!(questions_resolved(&snapshots[..1]).is_none());
+        assert_eq!(open_question_age(&snapshots[..1]), Some(0.0));
+    }
+
+    fn thoughts_snapshot(day: u32, content: &str) -> DocumentSnapshot {
+        DocumentSnapshot {
+            timestamp: format!("2026-03-{day:02}T10:00:00Z"),
+            documents: [(
+                "THOUGHTS.md".to_string(),
+                parser::h3_titles_by_section(content),
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn thought_resolution_time_is_median_days_to_settle() {
+        let snapshots = vec![
+            thoughts_snapshot(
+                1,
+                "## Active\n\n### Quick\n\n### Slow\n\n### Stuck\n\n## Graduated\n\n### Inherited\n",
+            ),
+            thoughts_snapshot(3, "## Active\n\n### Slow\n\n### Stuck\n\n## Graduated\n\n### Quick\n\n### Inherited\n"),
+            thoughts_snapshot(9, "## Active\n\n### Stuck\n\n## Graduated\n\n### Quick\n\n### Inherited\n\n## Dissolved\n\n### Slow\n"),
+        ];
+        // Quick took 2 days, Slow 8; Inherited was never seen active
+        assert_eq!(thought_resolution_time(&snapshots), Some(5.0));
+        assert!(thought_resolution_time(&snapshots[..1]).is_none());
+    }
+
+    #[test]
+    fn stale_thoughts_outlast_the_session_limit() {
+        let snapshots: Vec<DocumentSnapshot> = (1..=4)
+            .map(|day| {
+                let newer = if day >= 3 { "\n\n### Newer" } else { "" };
+                thoughts_snapshot(day, &format!("## Active\n\n### Older{newer}\n"))
+            })
+            .collect();
+        assert_eq!(stale_thoughts(&snapshots, 2), Some(1.0));
+        assert_eq!(stale_thought_lineages(&snapshots, 1)[0].title, "Older");
+        assert_eq!(stale_thoughts(&snapshots, 4), Some(0.0));
+    }
+
     #[test]
     fn thought_lifecycle_healthy() {
         let content = "## Active\n\n### Thought one\nContent\n\n### Thought two\nContent\n\n## Graduated\n\n### Done one\n\n### Done two\n\n### Done three\n\n## Dissolved\n\n### Gone one\n";
@@ -202,31 +696,146 @@ mod tests {
     #[test]
     fn evidence_grounding_scores() {
         let content = "## Observations\n\n### Grounded one\nD said something important on 2026-02-25 about identity.\n\n### Abstract one\nThinking is important and valuable in many ways.\n\n### Research one\nThe Reflexion paper showed that verbal self-evaluation helps.\n";
-        let score = evidence_grounding(content);
+        let score = evidence_grounding(content, &matcher());
         assert!(score.is_some());
         // 2 out of 3 entries grounded
         let s = score.unwrap();
         assert!((s - 2.0 / 3.0).abs() < 0.01);
     }
 
+    #[test]
+    fn hedging_balance_weighs_hedges_against_absolutes() {
+        let lexicon = HedgingLexicon::default();
+        let hedged =
+            "## Observations\n\n### One\nThis might work, perhaps, though I suspect not.\n";
+        let certain =
+            "## Observations\n\n### One\nThis clearly works and always will, obviously.\n";
+        assert!(hedging_balance(hedged, &lexicon).unwrap() > 0.0);
+        assert!(hedging_balance(certain, &lexicon).unwrap() < 0.0);
+        assert!(hedging_balance("", &lexicon).is_none());
+    }
+
+    #[test]
+    fn phrases_match_as_token_sequences() {
+        let tokens = parser::tokenize("Not sure; I'm not sure at all. Surely not.");
+        let counts = phrase_counts(&tokens, &["not sure".to_string(), "never".to_string()]);
+        assert_eq!(counts, vec![("not sure".to_string(), 2)]);
+    }
+
     #[test]
     fn evidence_grounding_empty() {
-        assert!(evidence_grounding("").is_none());
+        assert!(evidence_grounding("", &matcher()).is_none());
     }
 
     #[test]
     fn date_pattern_detection() {
-        assert!(has_date_pattern("something on 2026-02-27 happened"));
-        assert!(!has_date_pattern("no dates here at all"));
-        assert!(!has_date_pattern("20-02-27 not a date"));
+        let m = matcher().markers("something on 2026-02-27 happened");
+        assert_eq!(m[0].category, "dates");
+        assert_eq!(m[0].marker, "2026-02-27");
+        assert!(matcher().markers("no dates here at all").is_empty());
+        assert!(matcher().markers("20-02-27 not a date").is_empty());
     }
 
     #[test]
     fn evidence_markers() {
-        assert!(has_evidence("D said we should focus on this"));
-        assert!(has_evidence("The paper on metacognition was clear"));
-        assert!(has_evidence("During the session we discussed"));
-        assert!(has_evidence("On 2026-02-25 something happened"));
-        assert!(!has_evidence("Thinking is generally important"));
+        let m = matcher();
+        assert_eq!(m.credit("D said we should focus on this"), 1.0);
+        assert_eq!(m.credit("Maria pointed out the gap"), 1.0);
+        assert_eq!(m.credit("The paper on metacognition was clear"), 1.0);
+        assert_eq!(m.credit("See https://example.org/notes for details"), 1.0);
+        assert_eq!(m.credit("During the session we discussed"), 1.0);
+        assert_eq!(m.credit("According to Foucault, power is productive"), 1.0);
+        assert_eq!(m.credit("As Foucault argued"), 0.0);
+        assert_eq!(m.credit("On 2026-02-25 something happened"), 1.0);
+        assert_eq!(m.credit("Thinking is generally important"), 0.0);
+        assert_eq!(m.credit("I said it was fine"), 0.0);
+    }
+
+    #[test]
+    fn attribution_needs_a_name_not_a_pronoun() {
+        let m = matcher();
+        let attributed = |text: &str| m.markers(text).iter().any(|e| e.category == "attribution");
+        assert!(attributed("D asked whether it mattered"));
+        assert!(attributed("Later Maria noted the gap"));
+        assert!(attributed("per Kahneman, slow thinking costs"));
+        assert!(!attributed("It said nothing new"));
+        assert!(!attributed("This suggested a pattern"));
+        assert!(!attributed("They told me otherwise"));
+        assert!(!attributed("I said it was fine"));
+    }
+
+    #[test]
+    fn citations_and_links_are_evidence() {
+        let m = matcher();
+        let categories = |text: &str| -> Vec<String> {
+            m.markers(text).into_iter().map(|e| e.category).collect()
+        };
+        assert!(
+            categories("see [the notes](notes/today) here").contains(&"markdown_links".to_string())
+        );
+        assert!(categories("doi 10.1145/3292500.3330701 shows").contains(&"dois".to_string()));
+        assert!(categories("per arXiv:2303.11366v2").contains(&"arxiv_ids".to_string()));
+        assert!(
+            categories("she wrote \"the map is not the territory, ever\"")
+                .contains(&"quotes".to_string())
+        );
+        let counts = m.counts("https://a.example and https://b.example");
+        assert!(counts.contains(&("urls".to_string(), 2)));
+    }
+
+    #[test]
+    fn verified_file_references_must_exist() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("NOTES.md"), "x").unwrap();
+        let categories = vec![EvidenceCategory {
+            name: "file_refs".to_string(),
+            keywords: Vec::new(),
+            patterns: vec![r"\b[\w./-]*\w\.\w+(?::\d+)?".to_string()],
+            weight: 1.0,
+            verify_paths: true,
+            ignore: Vec::new(),
+        }];
+        let m = EvidenceMatcher::new(&categories, Some(dir.path())).unwrap();
+        assert_eq!(m.credit("see NOTES.md:12"), 1.0);
+        assert_eq!(m.credit("see INVENTED.md:12"), 0.0);
+        let unbased = EvidenceMatcher::new(&categories, None).unwrap();
+        assert_eq!(unbased.credit("see NOTES.md"), 0.0);
+    }
+
+    #[test]
+    fn default_file_references_must_exist_and_not_be_tracked_documents() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/parser.rs"), "x").unwrap();
+        std::fs::write(dir.path().join("THOUGHTS.md"), "x").unwrap();
+        let m = EvidenceMatcher::new(&state::default_evidence(), Some(dir.path())).unwrap();
+        assert_eq!(m.credit("The bug was in src/parser.rs all along"), 1.0);
+        assert_eq!(m.credit("broken at src/parser.rs:123 again"), 1.0);
+        assert_eq!(m.credit("Updated THOUGHTS.md"), 0.0);
+        assert_eq!(m.credit("Tweaked config.json for Node.js"), 0.0);
+        assert_eq!(m.credit("see src/invented.rs:12"), 0.0);
+        assert_eq!(matcher().credit("The bug was in src/parser.rs"), 0.0);
+    }
+
+    #[test]
+    fn evidence_categories_come_from_config() {
+        let custom = vec![EvidenceCategory {
+            name: "tickets".to_string(),
+            keywords: vec!["JIRA".to_string()],
+            patterns: vec![r"#\d+".to_string()],
+            weight: 0.25,
+            verify_paths: false,
+            ignore: Vec::new(),
+        }];
+        let m = EvidenceMatcher::new(&custom, None).unwrap();
+        assert_eq!(m.credit("fixed in #42"), 0.25);
+        assert_eq!(m.markers("see jira")[0].marker, "jira");
+        assert_eq!(m.credit("The paper said so"), 0.0);
+
+        let broken = vec![EvidenceCategory {
+            patterns: vec!["(".to_string()],
+            ..custom[0].clone()
+        }];
+        assert!(EvidenceMatcher::new(&broken, None).is_err());
     }
 }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
@@ -13,23 +13,118 @@ pub struct SignalVector {
     pub signals: Signals,
     #[serde(default)]
     pub document_hashes: HashMap<String, String>,
+    /// Session inputs, e.g. "topic:philosophy" (`collect --tag` or front matter).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
-/// The 4 Phase-1 signals. Null means document was missing.
+/// Signal values for one collection. Null means document was missing.
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Signals {
     pub vocabulary_diversity: Option<f64>,
     pub question_generation: Option<f64>,
     pub thought_lifecycle: Option<f64>,
     pub evidence_grounding: Option<f64>,
+    #[serde(default)]
+    pub hedging_balance: Option<f64>,
+    #[serde(default)]
+    pub question_depth: Option<f64>,
+    #[serde(default)]
+    pub questions_opened: Option<f64>,
+    #[serde(default)]
+    pub questions_resolved: Option<f64>,
+    #[serde(default)]
+    pub questions_abandoned: Option<f64>,
+    #[serde(default)]
+    pub open_question_age: Option<f64>,
+    #[serde(default)]
+    pub thought_resolution_time: Option<f64>,
+    #[serde(default)]
+    pub stale_thoughts: Option<f64>,
 }
 
-/// Per-signal trend direction.
+impl Signals {
+    pub fn get(&self, name: &str) -> Option<f64> {
+        match name {
+            "vocabulary_diversity" => self.vocabulary_diversity,
+            "question_generation" => self.question_generation,
+            "thought_lifecycle" => self.thought_lifecycle,
+            "evidence_grounding" => self.evidence_grounding,
+            "hedging_balance" => self.hedging_balance,
+            "question_depth" => self.question_depth,
+            "questions_opened" => self.questions_opened,
+            "questions_resolved" => self.questions_resolved,
+            "questions_abandoned" => self.questions_abandoned,
+            "open_question_age" => self.open_question_age,
+            "thought_resolution_time" => self.thought_resolution_time,
+            "stale_thoughts" => self.stale_thoughts,
+            _ => None,
+        }
+    }
+
+    /// Set a signal by name; unknown names are ignored.
+    pub fn set(&mut self, name: &str, value: Option<f64>) {
+        let slot = match name {
+            "vocabulary_diversity" => &mut self.vocabulary_diversity,
+            "question_generation" => &mut self.question_generation,
+            "thought_lifecycle" => &mut self.thought_lifecycle,
+            "evidence_grounding" => &mut self.evidence_grounding,
+            "hedging_balance" => &mut self.hedging_balance,
+            "question_depth" => &mut self.question_depth,
+            "questions_opened" => &mut self.questions_opened,
+            "questions_resolved" => &mut self.questions_resolved,
+            "questions_abandoned" => &mut self.questions_abandoned,
+            "open_question_age" => &mut self.open_question_age,
+            "thought_resolution_time" => &mut self.thought_resolution_time,
+            "stale_thoughts" => &mut self.stale_thoughts,
+            _ => return,
+        };
+        *slot = value;
+    }
+}
+
+/// Per-signal trend, relative to the signal's health rather than raw direction.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum Trend {
     Improving,
     Stable,
-    Declining,
+    #[serde(alias = "Declining")]
+    Worsening,
+}
+
+/// Which direction of a signal is healthy.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Polarity {
+    HigherIsBetter,
+    LowerIsBetter,
+    /// Healthy inside [low, high]; moving away on either side is worsening.
+    TargetRange {
+        low: f64,
+        high: f64,
+    },
+}
+
+impl Polarity {
+    /// How much healthier `after` is than `before`, in the signal's units.
+    pub fn improvement(&self, before: f64, after: f64) -> f64 {
+        match self {
+            Polarity::HigherIsBetter => after - before,
+            Polarity::LowerIsBetter => before - after,
+            Polarity::TargetRange { low, high } => {
+                let distance = |v: f64| (low - v).max(v - high).max(0.0);
+                distance(before) - distance(after)
+            }
+        }
+    }
+}
+
+/// Health zone a signal value sits in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Zone {
+    Healthy,
+    Watch,
+    Concern,
 }
 
 /// Per-signal trend info.
@@ -38,11 +133,38 @@ pub struct SignalTrend {
     pub current: Option<f64>,
     pub trend: Trend,
     pub delta: f64,
+    /// Delta oriented by polarity: positive is healthier.
+    #[serde(default)]
+    pub health_delta: f64,
+    /// Mann-Kendall two-sided p-value over the window.
+    #[serde(default)]
+    pub p_value: Option<f64>,
+    /// Theil-Sen slope over the window (change per collection).
+    #[serde(default)]
+    pub slope: Option<f64>,
+    /// Health zone of the current value, if the signal has zone cutoffs.
+    #[serde(default)]
+    pub zone: Option<Zone>,
+    /// Watch message raised for this signal, if any.
+    #[serde(default)]
+    pub message: Option<String>,
 }
 
-/// Alert level.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// A sudden shift in a signal's level found by change-point detection.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChangePoint {
+    pub signal: String,
+    /// Index into the signal history where the new regime starts.
+    pub index: usize,
+    pub timestamp: String,
+    /// Mean after the change minus mean before it.
+    pub shift: f64,
+}
+
+/// Alert level, ordered from least to most severe.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum AlertLevel {
+    #[default]
     Healthy,
     Watch,
     Concern,
@@ -57,10 +179,38 @@ pub struct Analysis {
     pub signals: HashMap<String, SignalTrend>,
     pub improving_count: usize,
     pub stable_count: usize,
-    pub declining_count: usize,
+    #[serde(alias = "declining_count")]
+    pub worsening_count: usize,
     pub highlight: Option<String>,
     pub watch_messages: Vec<String>,
     pub data_points: usize,
+    #[serde(default)]
+    pub change_points: Vec<ChangePoint>,
+    /// Weighted 0–100 cognitive health score of the latest collection.
+    #[serde(default)]
+    pub composite_score: Option<f64>,
+}
+
+/// How a signal's trend is labelled.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendMethod {
+    /// Mean of the last 3 points vs mean of the rest of the window.
+    #[default]
+    MeanDelta,
+    /// Mann-Kendall significance plus Theil-Sen slope over the whole window.
+    MannKendall,
+}
+
+/// Where decline/improve bands come from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdMode {
+    /// The fixed `thresholds` pairs.
+    #[default]
+    Fixed,
+    /// EWMA baseline with bands of `adaptive_k` × the signal's standard deviation.
+    Adaptive,
 }
 
 /// Configuration with thresholds.
@@ -71,6 +221,393 @@ pub struct Config {
     pub max_history: usize,
     pub alert_after_sessions: usize,
     pub cooldown_seconds: u64,
+    #[serde(default)]
+    pub trend_method: TrendMethod,
+    /// p-value below which a Mann-Kendall trend counts as real.
+    #[serde(default = "default_trend_significance")]
+    pub trend_significance: f64,
+    /// CUSUM allowance, in noise standard deviations.
+    #[serde(default = "default_change_point_k")]
+    pub change_point_k: f64,
+    /// CUSUM decision interval, in noise standard deviations.
+    #[serde(default = "default_change_point_h")]
+    pub change_point_h: f64,
+    #[serde(default)]
+    pub threshold_mode: ThresholdMode,
+    /// Weight of each newer value in the adaptive EWMA baseline.
+    #[serde(default = "default_ewma_alpha")]
+    pub ewma_alpha: f64,
+    /// Adaptive band width, in standard deviations.
+    #[serde(default = "default_adaptive_k")]
+    pub adaptive_k: f64,
+    #[serde(default = "default_zones")]
+    pub zones: HashMap<String, ZoneBounds>,
+    /// Signals missing here are higher-is-better.
+    #[serde(default = "default_polarity")]
+    pub polarity: HashMap<String, Polarity>,
+    /// Weight of each signal in the composite health score (missing = 1.0).
+    #[serde(default = "default_weights")]
+    pub weights: HashMap<String, f64>,
+    /// Consecutive sessions at a higher level before the alert escalates.
+    #[serde(default = "default_alert_escalate_after")]
+    pub alert_escalate_after: usize,
+    /// Consecutive sessions at a lower level before the alert clears.
+    #[serde(default = "default_alert_clear_after")]
+    pub alert_clear_after: usize,
+    /// Markers that count as evidence in evidence_grounding, by category.
+    #[serde(default = "default_evidence")]
+    pub evidence: Vec<EvidenceCategory>,
+    /// Words and phrases hedging_balance counts.
+    #[serde(default)]
+    pub hedging: HedgingLexicon,
+    /// Consecutive collections in Active after which a thought is stale.
+    #[serde(default = "default_stale_thought_sessions")]
+    pub stale_thought_sessions: usize,
+}
+
+impl Config {
+    /// Give signals added after the config was written (those without a
+    /// threshold entry) their default thresholds, zones and polarity.
+    fn add_missing_signals(&mut self) {
+        let defaults = Config::default();
+        for (name, threshold) in defaults.thresholds {
+            if self.thresholds.contains_key(&name) {
+                continue;
+            }
+            if let Some(zone) = defaults.zones.get(&name) {
+                self.zones.entry(name.clone()).or_insert(zone.clone());
+            }
+            if let Some(polarity) = defaults.polarity.get(&name) {
+                self.polarity
+                    .entry(name.clone())
+                    .or_insert(polarity.clone());
+            }
+            self.thresholds.insert(name, threshold);
+        }
+    }
+
+    pub fn polarity_of(&self, name: &str) -> Polarity {
+        self.polarity
+            .get(name)
+            .cloned()
+            .unwrap_or(Polarity::HigherIsBetter)
+    }
+}
+
+fn default_trend_significance() -> f64 {
+    0.05
+}
+
+fn default_change_point_k() -> f64 {
+    0.5
+}
+
+fn default_change_point_h() -> f64 {
+    4.0
+}
+
+fn default_ewma_alpha() -> f64 {
+    0.3
+}
+
+fn default_adaptive_k() -> f64 {
+    1.0
+}
+
+/// Healthy hedging_balance: hedges at most 3 per 100 words ahead of absolutes.
+/// Its yellow zone starts at the edges of this range.
+const HEDGING_RANGE: (f64, f64) = (0.0, 3.0);
+
+/// How far past HEDGING_RANGE the red zone starts, below and above. Wider on
+/// the hedged side: a few extra qualifiers are milder than outright certainty.
+const HEDGING_CONCERN_MARGIN: (f64, f64) = (1.0, 2.0);
+
+fn default_polarity() -> HashMap<String, Polarity> {
+    let mut polarity: HashMap<String, Polarity> = [
+        "vocabulary_diversity",
+        "question_generation",
+        "thought_lifecycle",
+        "evidence_grounding",
+        "question_depth",
+        "questions_opened",
+        "questions_resolved",
+    ]
+    .into_iter()
+    .map(|name| (name.to_string(), Polarity::HigherIsBetter))
+    .collect();
+    for name in [
+        "questions_abandoned",
+        "open_question_age",
+        "thought_resolution_time",
+        "stale_thoughts",
+    ] {
+        polarity.insert(name.to_string(), Polarity::LowerIsBetter);
+    }
+    // Overconfident below, mush-mouthed above
+    let (low, high) = HEDGING_RANGE;
+    polarity.insert(
+        "hedging_balance".to_string(),
+        Polarity::TargetRange { low, high },
+    );
+    polarity
+}
+
+fn default_alert_escalate_after() -> usize {
+    2
+}
+
+fn default_alert_clear_after() -> usize {
+    3
+}
+
+fn default_stale_thought_sessions() -> usize {
+    10
+}
+
+fn default_weights() -> HashMap<String, f64> {
+    [
+        "vocabulary_diversity",
+        "question_generation",
+        "thought_lifecycle",
+        "evidence_grounding",
+        "hedging_balance",
+        "question_depth",
+        "questions_opened",
+        "questions_resolved",
+        "questions_abandoned",
+        "open_question_age",
+        "thought_resolution_time",
+        "stale_thoughts",
+    ]
+    .into_iter()
+    .map(|name| (name.to_string(), 1.0))
+    .collect()
+}
+
+fn default_zones() -> HashMap<String, ZoneBounds> {
+    let mut zones = HashMap::new();
+    for (name, concern_below, watch_below) in [
+        ("vocabulary_diversity", 0.25, 0.40),
+        ("question_generation", 2.0, 4.0),
+        ("thought_lifecycle", 0.15, 0.30),
+        ("evidence_grounding", 0.40, 0.60),
+        ("question_depth", 0.20, 0.35),
+    ] {
+        zones.insert(
+            name.to_string(),
+            ZoneBounds {
+                concern_below: Some(concern_below),
+                watch_below: Some(watch_below),
+                ..ZoneBounds::default()
+            },
+        );
+    }
+    let (low, high) = HEDGING_RANGE;
+    let (below, above) = HEDGING_CONCERN_MARGIN;
+    zones.insert(
+        "hedging_balance".to_string(),
+        ZoneBounds {
+            concern_below: Some(low - below),
+            watch_below: Some(low),
+            watch_above: Some(high),
+            concern_above: Some(high + above),
+        },
+    );
+    zones.insert(
+        "open_question_age".to_string(),
+        ZoneBounds {
+            watch_above: Some(30.0),
+            concern_above: Some(60.0),
+            ..ZoneBounds::default()
+        },
+    );
+    zones.insert(
+        "stale_thoughts".to_string(),
+        ZoneBounds {
+            watch_above: Some(2.0),
+            concern_above: Some(4.0),
+            ..ZoneBounds::default()
+        },
+    );
+    zones
+}
+
+/// One category of evidence marker. An entry matches the category when it
+/// contains any keyword (ignoring case) or matches any regex pattern.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EvidenceCategory {
+    pub name: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Grounding credit for a match; an entry's total is capped at 1.0.
+    #[serde(default = "default_evidence_weight")]
+    pub weight: f64,
+    /// Only count pattern matches naming a file that exists under the docs
+    /// directory (a trailing `:line` is ignored), so invented references
+    /// earn nothing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub verify_paths: bool,
+    /// Pattern matches containing any of these words (ignoring case) don't
+    /// count, e.g. pronouns that look like names at the start of a sentence.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+}
+
+fn default_evidence_weight() -> f64 {
+    1.0
+}
+
+pub fn default_evidence() -> Vec<EvidenceCategory> {
+    let tracked_documents = || {
+        ["reflections", "thoughts", "curiosity"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>()
+    };
+    let category =
+        |name: &str, keywords: &[&str], patterns: &[&str], weight: f64| EvidenceCategory {
+            name: name.to_string(),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            weight,
+            verify_paths: false,
+            ignore: Vec::new(),
+        };
+    vec![
+        category("dates", &[], &[r"\b\d{4}-\d{2}-\d{2}\b"], 1.0),
+        EvidenceCategory {
+            ignore: [
+                "it", "this", "that", "these", "those", "he", "she", "they", "we", "you", "one",
+                "there", "which", "who", "what", "everyone", "someone", "nobody", "the", "an",
+            ]
+            .iter()
+            .map(|w| w.to_string())
+            .collect(),
+            // A capitalized name (or initial other than "I") reporting
+            // something, or an explicit "per <Name>"
+            ..category(
+                "attribution",
+                &[],
+                &[
+                    r"\b(?:[A-HJ-Z]|[A-Z][a-z]+) (?:said|asked|mentioned|told|called|pointed out|suggested|noted)\b",
+                    r"\b[Pp]er [A-Z][\w.-]+",
+                ],
+                1.0,
+            )
+        },
+        category(
+            "citations",
+            &[
+                "paper",
+                "research",
+                "study",
+                "found that",
+                "according to",
+                "framework",
+            ],
+            &[],
+            1.0,
+        ),
+        category("markdown_links", &[], &[r"\[[^\]]+\]\([^)\s]+\)"], 1.0),
+        category("urls", &[], &[r"https?://[^\s)>\]]+"], 1.0),
+        category("dois", &[], &[r#"\b10\.\d{4,9}/[^\s"<>)\]]+"#], 1.0),
+        category(
+            "arxiv_ids",
+            &[],
+            &[r"(?i)\barxiv:\s?\d{4}\.\d{4,5}(?:v\d+)?\b"],
+            1.0,
+        ),
+        // Only files that exist under the docs directory count, and never the
+        // documents being measured, so "updated THOUGHTS.md" or "Node.js"
+        // ground nothing
+        EvidenceCategory {
+            verify_paths: true,
+            ignore: tracked_documents(),
+            ..category("file_refs", &[], &[r"\b[\w./-]*\w\.\w+:\d+\b"], 1.0)
+        },
+        EvidenceCategory {
+            verify_paths: true,
+            ignore: tracked_documents(),
+            ..category(
+                "file_paths",
+                &[],
+                &[r"\b[\w./-]*\w\.(?:rs|py|ts|js|go|md|toml|json|ya?ml|sh|txt)\b"],
+                1.0,
+            )
+        },
+        // Quoted passages of a few words or more
+        category("quotes", &[], &[r#""[^"]{20,}"|“[^”]{20,}”"#], 0.5),
+        category(
+            "session_events",
+            &[
+                "session",
+                "conversation",
+                "call with",
+                "during the",
+                "yesterday",
+                "this morning",
+                "last night",
+            ],
+            &[],
+            1.0,
+        ),
+    ]
+}
+
+/// Lexicons for hedging_balance. Entries are tokenized the same way as the
+/// text, so "I suspect" matches as "suspect" (single letters are dropped).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HedgingLexicon {
+    pub hedges: Vec<String>,
+    pub absolutes: Vec<String>,
+}
+
+impl Default for HedgingLexicon {
+    fn default() -> Self {
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect();
+        HedgingLexicon {
+            hedges: words(&[
+                "might",
+                "perhaps",
+                "maybe",
+                "possibly",
+                "probably",
+                "likely",
+                "seems",
+                "seemed",
+                "appears",
+                "I suspect",
+                "suggests",
+                "arguably",
+                "tentatively",
+                "uncertain",
+                "unclear",
+                "not sure",
+                "could be",
+                "may be",
+            ]),
+            absolutes: words(&[
+                "always",
+                "never",
+                "clearly",
+                "definitely",
+                "certainly",
+                "obviously",
+                "undoubtedly",
+                "undeniably",
+                "absolutely",
+                "no doubt",
+                "without doubt",
+                "of course",
+                "everyone",
+                "nobody",
+                "proves",
+                "must",
+            ]),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -79,6 +616,80 @@ pub struct ThresholdPair {
     pub improve: f64,
 }
 
+/// Health-zone cutoffs for one signal. Higher-is-better signals use the
+/// `_below` cutoffs, lower-is-better ones the `_above` cutoffs, and
+/// target-range signals both.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ZoneBounds {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concern_below: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_below: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_above: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concern_above: Option<f64>,
+}
+
+impl ZoneBounds {
+    pub fn zone(&self, value: f64) -> Zone {
+        let below = |cutoff: Option<f64>| cutoff.is_some_and(|c| value < c);
+        let above = |cutoff: Option<f64>| cutoff.is_some_and(|c| value > c);
+        if below(self.concern_below) || above(self.concern_above) {
+            Zone::Concern
+        } else if below(self.watch_below) || above(self.watch_above) {
+            Zone::Watch
+        } else {
+            Zone::Healthy
+        }
+    }
+    /// Position of `value` between the zones: 1.0 at or beyond the yellow
+    /// cutoff on the healthy side, 0.5 on the red cutoff, falling to 0.0 one
+    /// zone-width deeper into the red. None if the signal has no cutoffs.
+    pub fn position(&self, value: f64) -> Option<f64> {
+        // `sign` is +1.0 when healthy values lie above the cutoffs, -1.0 when below
+        let side = |concern: Option<f64>, watch: Option<f64>, sign: f64| -> Option<f64> {
+            match (concern, watch) {
+                (Some(c), Some(w)) => {
+                    let gap = (w - c).abs().max(f64::EPSILON);
+                    Some((0.5 + 0.5 * (value - c) * sign / gap).clamp(0.0, 1.0))
+                }
+                (Some(c), None) => Some(if (value - c) * sign >= 0.0 { 1.0 } else { 0.0 }),
+                (None, Some(w)) => Some(if (value - w) * sign >= 0.0 { 1.0 } else { 0.5 }),
+                (None, None) => None,
+            }
+        };
+        let below = side(self.concern_below, self.watch_below, 1.0);
+        let above = side(self.concern_above, self.watch_above, -1.0);
+        match (below, above) {
+            (Some(b), Some(a)) => Some(b.min(a)),
+            (b, a) => b.or(a),
+        }
+    }
+
+    /// Human-readable cutoffs, e.g. "red < 0.25, yellow < 0.40".
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(c) = self.concern_below {
+            parts.push(format!("red < {c:.2}"));
+        }
+        if let Some(c) = self.watch_below {
+            parts.push(format!("yellow < {c:.2}"));
+        }
+        if let Some(c) = self.watch_above {
+            parts.push(format!("yellow > {c:.2}"));
+        }
+        if let Some(c) = self.concern_above {
+            parts.push(format!("red > {c:.2}"));
+        }
+        if parts.is_empty() {
+            "no cutoffs".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         let mut thresholds = HashMap::new();
@@ -110,14 +721,224 @@ impl Default for Config {
                 improve: 0.10,
             },
         );
+        thresholds.insert(
+            "hedging_balance".to_string(),
+            ThresholdPair {
+                decline: -0.5,
+                improve: 0.5,
+            },
+        );
+        thresholds.insert(
+            "question_depth".to_string(),
+            ThresholdPair {
+                decline: -0.10,
+                improve: 0.10,
+            },
+        );
+        // Flows rather than stocks: no zones, so they stay out of composite health
+        for name in [
+            "questions_opened",
+            "questions_resolved",
+            "questions_abandoned",
+        ] {
+            thresholds.insert(
+                name.to_string(),
+                ThresholdPair {
+                    decline: -1.0,
+                    improve: 1.0,
+                },
+            );
+        }
+        thresholds.insert(
+            "open_question_age".to_string(),
+            ThresholdPair {
+                decline: -3.0,
+                improve: 3.0,
+            },
+        );
+        thresholds.insert(
+            "thought_resolution_time".to_string(),
+            ThresholdPair {
+                decline: -3.0,
+                improve: 3.0,
+            },
+        );
+        thresholds.insert(
+            "stale_thoughts".to_string(),
+            ThresholdPair {
+                decline: -1.0,
+                improve: 1.0,
+            },
+        );
         Config {
             thresholds,
             window_size: 10,
             max_history: 50,
             alert_after_sessions: 7,
             cooldown_seconds: 60,
+            trend_method: TrendMethod::default(),
+            trend_significance: default_trend_significance(),
+            change_point_k: default_change_point_k(),
+            change_point_h: default_change_point_h(),
+            threshold_mode: ThresholdMode::default(),
+            ewma_alpha: default_ewma_alpha(),
+            adaptive_k: default_adaptive_k(),
+            zones: default_zones(),
+            polarity: default_polarity(),
+            weights: default_weights(),
+            alert_escalate_after: default_alert_escalate_after(),
+            alert_clear_after: default_alert_clear_after(),
+            evidence: default_evidence(),
+            hedging: HedgingLexicon::default(),
+            stale_thought_sessions: default_stale_thought_sessions(),
+        }
+    }
+}
+
+/// One change of the persisted alert level.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AlertTransition {
+    pub timestamp: String,
+    pub from: AlertLevel,
+    pub to: AlertLevel,
+    /// Signals worsening or in the red zone when the level changed.
+    pub signals: Vec<String>,
+    pub reason: String,
+}
+
+/// Persisted alert state machine (alert-state.json).
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AlertState {
+    pub level: AlertLevel,
+    /// When the current level was entered.
+    pub since: Option<String>,
+    /// Signals contributing to the current level.
+    pub signals: Vec<String>,
+    /// Level the latest sessions point to while waiting out hysteresis.
+    pub pending: Option<AlertLevel>,
+    pub pending_count: usize,
+    /// Consecutive sessions spent at the current level.
+    pub sessions_at_level: usize,
+    /// Consecutive worsening sessions per signal.
+    pub streaks: HashMap<String, usize>,
+    /// Timestamp of the last collection fed into the machine.
+    pub last_evaluated: Option<String>,
+    pub transitions: Vec<AlertTransition>,
+    /// Conditions a human has acknowledged (`vigil-echo ack`).
+    #[serde(default)]
+    pub acks: Vec<Ack>,
+}
+
+/// An acknowledged alert condition, either the overall level or one signal.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Ack {
+    pub created: String,
+    /// Snooze end; the ack lapses after this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Signal acknowledged; `None` acknowledges the overall level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<String>,
+    pub note: String,
+    /// Alert level when acknowledged.
+    pub level: AlertLevel,
+    /// Zone and value of the signal when acknowledged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<Zone>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+/// A known event on the timeline (annotations.json).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Annotation {
+    pub timestamp: String,
+    pub note: String,
+    /// Start trend baselines afresh from this point.
+    #[serde(default)]
+    pub reset_baseline: bool,
+}
+
+/// ### entry titles per ## section of each document at one collection
+/// (snapshots.json), used to report new and graduated entries. Sections
+/// keep their document order, so an entry listed twice is credited to the
+/// section it appears in first.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DocumentSnapshot {
+    pub timestamp: String,
+    #[serde(with = "ordered_sections")]
+    pub documents: BTreeMap<String, Vec<(String, Vec<String>)>>,
+}
+
+/// Reads and writes each document's sections as a JSON object, as
+/// snapshots.json always has, while keeping them in file order.
+mod ordered_sections {
+    use serde::de::{MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    type Sections = Vec<(String, Vec<String>)>;
+
+    struct Ordered<'a>(&'a Sections);
+
+    impl Serialize for Ordered<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(self.0.iter().map(|(section, titles)| (section, titles)))
         }
     }
+
+    struct Owned(Sections);
+
+    impl<'de> Deserialize<'de> for Owned {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct SectionsVisitor;
+
+            impl<'de> Visitor<'de> for SectionsVisitor {
+                type Value = Owned;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a map of section names to entry titles")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Owned, A::Error> {
+                    let mut sections = Vec::new();
+                    while let Some(entry) = map.next_entry()? {
+                        sections.push(entry);
+                    }
+                    Ok(Owned(sections))
+                }
+            }
+
+            deserializer.deserialize_map(SectionsVisitor)
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        documents: &BTreeMap<String, Sections>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            documents
+                .iter()
+                .map(|(doc, sections)| (doc, Ordered(sections))),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<String, Sections>, D::Error> {
+        let documents = BTreeMap::<String, Owned>::deserialize(deserializer)?;
+        Ok(documents.into_iter().map(|(doc, s)| (doc, s.0)).collect())
+    }
+}
+
+/// A named regime of how the agent works (epochs.json). Each epoch runs
+/// until the next one starts.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Epoch {
+    pub name: String,
+    pub started: String,
 }
 
 /// Pulse state (last run time for cooldown).
@@ -176,7 +997,17 @@ pub fn load_config() -> Result<Config, String> {
         return Ok(Config::default());
     }
     let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read config: {e}"))?;
-    serde_json::from_str(&content).map_err(|e| format!("Failed to parse config: {e}"))
+    let mut config: Config =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse config: {e}"))?;
+    config.add_missing_signals();
+    Ok(config)
+}
+
+pub fn save_config(config: &Config) -> Result<(), String> {
+    let path = paths::config_file()?;
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize config: {e}"))?;
+    fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write config: {e}"))
 }
 
 pub fn load_pulse_state() -> Result<PulseState, String> {
@@ -196,13 +1027,84 @@ pub fn save_pulse_state(state: &PulseState) -> Result<(), String> {
     fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write pulse state: {e}"))
 }
 
+pub fn load_alert_state() -> Result<AlertState, String> {
+    let path = paths::alert_state_file()?;
+    if !path.exists() {
+        return Ok(AlertState::default());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read alert state: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse alert state: {e}"))
+}
+
+pub fn save_alert_state(state: &AlertState) -> Result<(), String> {
+    let path = paths::alert_state_file()?;
+    let json = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize alert state: {e}"))?;
+    fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write alert state: {e}"))
+}
+
+pub fn load_annotations() -> Result<Vec<Annotation>, String> {
+    let path = paths::annotations_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read annotations: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse annotations: {e}"))
+}
+
+pub fn save_annotations(annotations: &[Annotation]) -> Result<(), String> {
+    let path = paths::annotations_file()?;
+    let json = serde_json::to_string_pretty(annotations)
+        .map_err(|e| format!("Failed to serialize annotations: {e}"))?;
+    fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write annotations: {e}"))
+}
+
+pub fn load_snapshots() -> Result<Vec<DocumentSnapshot>, String> {
+    let path = paths::snapshots_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read snapshots: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse snapshots: {e}"))
+}
+
+pub fn save_snapshots(snapshots: &[DocumentSnapshot]) -> Result<(), String> {
+    let path = paths::snapshots_file()?;
+    let json = serde_json::to_string_pretty(snapshots)
+        .map_err(|e| format!("Failed to serialize snapshots: {e}"))?;
+    fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write snapshots: {e}"))
+}
+
+pub fn load_epochs() -> Result<Vec<Epoch>, String> {
+    let path = paths::epochs_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read epochs: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse epochs: {e}"))
+}
+
+pub fn save_epochs(epochs: &[Epoch]) -> Result<(), String> {
+    let path = paths::epochs_file()?;
+    let json = serde_json::to_string_pretty(epochs)
+        .map_err(|e| format!("Failed to serialize epochs: {e}"))?;
+    fs::write(path, format!("{json}\n")).map_err(|e| format!("Failed to write epochs: {e}"))
+}
+
 // --- Timestamp helpers (no chrono dependency) ---
 
 pub fn now_iso() -> String {
     let duration = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default();
-    let secs = duration.as_secs();
+    epoch_to_iso(duration.as_secs())
+}
+
+/// Format epoch seconds as "YYYY-MM-DDThh:mm:ssZ".
+pub fn epoch_to_iso(secs: u64) -> String {
     let days = secs / 86400;
     let time_secs = secs % 86400;
     let hours = time_secs / 3600;
@@ -222,20 +1124,60 @@ pub fn now_epoch_secs() -> u64 {
         .as_secs()
 }
 
+/// Parse "YYYY-MM-DDThh:mm:ssZ" to rough epoch seconds. None for anything
+/// unreadable, including dates before 1970.
 pub fn parse_iso_epoch(ts: &str) -> Option<u64> {
-    // Parse "YYYY-MM-DDThh:mm:ssZ" to rough epoch seconds
-    if ts.len() < 19 {
-        return None;
-    }
-    let year: u64 = ts[..4].parse().ok()?;
-    let month: u64 = ts[5..7].parse().ok()?;
-    let day: u64 = ts[8..10].parse().ok()?;
-    let hours: u64 = ts[11..13].parse().ok()?;
-    let minutes: u64 = ts[14..16].parse().ok()?;
-    let seconds: u64 = ts[17..19].parse().ok()?;
+    let field = |r: std::ops::Range<usize>| ts.get(r)?.parse::<u64>().ok();
+    let year = field(0..4)?;
+    let month = field(5..7)?;
+    let day = field(8..10)?;
+    let hours = field(11..13)?;
+    let minutes = field(14..16)?;
+    let seconds = field(17..19)?;
 
-    let days = date_to_days(year, month, day);
-    Some(days * 86400 + hours * 3600 + minutes * 60 + seconds)
+    let days = date_to_days(year, month, day)?;
+    days.checked_mul(86400)?
+        .checked_add(hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Parse a command-line timestamp: "YYYY-MM-DD" or "YYYY-MM-DDThh:mm:ssZ".
+/// A bare date means the start of the day, or its end when `end_of_day` is set.
+pub fn parse_timestamp_arg(input: &str, end_of_day: bool) -> Result<String, String> {
+    let ts = match (input.len(), end_of_day) {
+        (10, true) => format!("{input}T23:59:59Z"),
+        (10, false) => format!("{input}T00:00:00Z"),
+        _ => input.to_string(),
+    };
+    let field = |r: std::ops::Range<usize>| ts.get(r).and_then(|f| f.parse::<u32>().ok());
+    let in_range = matches!(
+        (
+            field(5..7),
+            field(8..10),
+            field(11..13),
+            field(14..16),
+            field(17..19)
+        ),
+        (
+            Some(1..=12),
+            Some(1..=31),
+            Some(0..=23),
+            Some(0..=59),
+            Some(0..=59)
+        )
+    );
+    if in_range && field(0..4).is_some_and(|y| y < 1970) {
+        Err(format!(
+            "Invalid timestamp '{input}' (dates before 1970-01-01 are not supported)"
+        ))
+    } else if parse_iso_epoch(&ts).is_some_and(|epoch| epoch_to_iso(epoch) == ts) {
+        // The round trip rejects impossible dates such as February 31st,
+        // which the day arithmetic would otherwise roll into March
+        Ok(ts)
+    } else {
+        Err(format!(
+            "Invalid timestamp '{input}' (expected YYYY-MM-DD or YYYY-MM-DDThh:mm:ssZ)"
+        ))
+    }
 }
 
 fn days_to_date(days_since_epoch: u64) -> (u64, u64, u64) {
@@ -252,12 +1194,153 @@ fn days_to_date(days_since_epoch: u64) -> (u64, u64, u64) {
     (year, m, d)
 }
 
-fn date_to_days(year: u64, month: u64, day: u64) -> u64 {
-    let y = if month <= 2 { year - 1 } else { year };
+/// Days since 1970-01-01, or None for dates before it.
+fn date_to_days(year: u64, month: u64, day: u64) -> Option<u64> {
+    let y = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
     let m = if month <= 2 { month + 9 } else { month - 3 };
     let era = y / 400;
     let yoe = y - era * 400;
-    let doy = (153 * m + 2) / 5 + day - 1;
+    let doy = ((153 * m + 2) / 5 + day).checked_sub(1)?;
     let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
-    era * 146097 + doe - 719468
+    (era * 146097 + doe).checked_sub(719468)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polarity_improvement() {
+        assert_eq!(Polarity::HigherIsBetter.improvement(1.0, 2.0), 1.0);
+        assert_eq!(Polarity::LowerIsBetter.improvement(1.0, 2.0), -1.0);
+        let range = Polarity::TargetRange {
+            low: 2.0,
+            high: 4.0,
+        };
+        assert_eq!(range.improvement(1.0, 3.0), 1.0);
+        assert_eq!(range.improvement(3.0, 6.0), -2.0);
+        assert_eq!(range.improvement(2.5, 3.5), 0.0);
+    }
+
+    #[test]
+    fn hedging_zones_follow_its_target_range() {
+        let config = Config::default();
+        let Polarity::TargetRange { low, high } = config.polarity_of("hedging_balance") else {
+            panic!("hedging_balance should be a target range");
+        };
+        let zones = &config.zones["hedging_balance"];
+        assert_eq!(zones.zone(low), Zone::Healthy);
+        assert_eq!(zones.zone(high), Zone::Healthy);
+        assert_eq!(zones.zone(low - 0.5), Zone::Watch);
+        assert_eq!(zones.zone(high + 0.5), Zone::Watch);
+        assert_eq!(
+            zones.zone(zones.concern_below.unwrap() - 0.1),
+            Zone::Concern
+        );
+        assert_eq!(
+            zones.zone(zones.concern_above.unwrap() + 0.1),
+            Zone::Concern
+        );
+    }
+
+    #[test]
+    fn zone_bounds_both_sides() {
+        let bounds = ZoneBounds {
+            concern_below: Some(1.0),
+            watch_below: Some(2.0),
+            watch_above: Some(4.0),
+            concern_above: Some(5.0),
+        };
+        assert_eq!(bounds.zone(0.5), Zone::Concern);
+        assert_eq!(bounds.zone(1.5), Zone::Watch);
+        assert_eq!(bounds.zone(3.0), Zone::Healthy);
+        assert_eq!(bounds.zone(4.5), Zone::Watch);
+        assert_eq!(bounds.zone(6.0), Zone::Concern);
+    }
+
+    #[test]
+    fn zone_position_scales_between_cutoffs() {
+        let below = ZoneBounds {
+            concern_below: Some(2.0),
+            watch_below: Some(4.0),
+            ..ZoneBounds::default()
+        };
+        assert_eq!(below.position(5.0), Some(1.0));
+        assert_eq!(below.position(3.0), Some(0.75));
+        assert_eq!(below.position(2.0), Some(0.5));
+        assert_eq!(below.position(1.0), Some(0.25));
+        assert_eq!(below.position(-1.0), Some(0.0));
+
+        let above = ZoneBounds {
+            watch_above: Some(4.0),
+            concern_above: Some(6.0),
+            ..ZoneBounds::default()
+        };
+        assert_eq!(above.position(3.0), Some(1.0));
+        assert_eq!(above.position(5.0), Some(0.75));
+        assert_eq!(above.position(7.0), Some(0.25));
+
+        assert_eq!(ZoneBounds::default().position(1.0), None);
+    }
+
+    #[test]
+    fn old_analysis_names_still_parse() {
+        let trend: Trend = serde_json::from_str("\"Declining\"").unwrap();
+        assert_eq!(trend, Trend::Worsening);
+    }
+
+    #[test]
+    fn snapshots_keep_section_order() {
+        let json = r#"{"timestamp":"2026-03-01T10:00:00Z","documents":{"CURIOSITY.md":{"Open Questions":["Why?"],"Explored":["Why?"]}}}"#;
+        let snapshot: DocumentSnapshot = serde_json::from_str(json).unwrap();
+        let sections: Vec<&str> = snapshot.documents["CURIOSITY.md"]
+            .iter()
+            .map(|(s, _)| s.as_str())
+            .collect();
+        assert_eq!(sections, vec!["Open Questions", "Explored"]);
+        assert_eq!(serde_json::to_string(&snapshot).unwrap(), json);
+    }
+
+    #[test]
+    fn timestamp_args_accept_bare_dates() {
+        assert_eq!(
+            parse_timestamp_arg("2026-04-01", true).unwrap(),
+            "2026-04-01T23:59:59Z"
+        );
+        assert_eq!(
+            parse_timestamp_arg("2026-04-01", false).unwrap(),
+            "2026-04-01T00:00:00Z"
+        );
+        assert!(parse_timestamp_arg("2026-04-01T08:00:00Z", true).is_ok());
+        assert!(parse_timestamp_arg("next week", true).is_err());
+        assert!(parse_timestamp_arg("2026-13-40", true).is_err());
+        assert!(parse_timestamp_arg("2026-02-31", false).is_err());
+        assert!(parse_timestamp_arg("2024-02-29", false).is_ok());
+        assert!(parse_timestamp_arg("2026-02-29T10:00:00Z", false).is_err());
+    }
+
+    #[test]
+    fn timestamps_before_1970_are_rejected() {
+        for input in ["1969-12-31", "0000-01-01", "1960-06-01T12:00:00Z"] {
+            let err = parse_timestamp_arg(input, false).unwrap_err();
+            assert!(err.contains("before 1970"), "{input}: {err}");
+        }
+        assert!(parse_timestamp_arg("1970-01-01", false).is_ok());
+        assert_eq!(parse_iso_epoch("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse_iso_epoch("0000-01-01T00:00:00Z"), None);
+        assert_eq!(parse_iso_epoch("1970-01-01T00:00:00Z"), Some(0));
+    }
+
+    #[test]
+    fn malformed_timestamps_parse_to_none() {
+        assert_eq!(parse_iso_epoch("2026é03-01T10:00:00Z"), None);
+        assert_eq!(parse_iso_epoch("2026-03-01T10:0é:00Z"), None);
+        assert_eq!(parse_iso_epoch("2026-03-01"), None);
+        assert_eq!(parse_iso_epoch("+026-03-01T10:00:00Z"), None);
+        assert!(parse_iso_epoch("2026-03-01T10:00:00Z").is_some());
+    }
 }
@@ -6,13 +6,7 @@ const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█
 pub fn signal_series(history: &[SignalVector], name: &str) -> Vec<f64> {
     history
         .iter()
-        .filter_map(|sv| match name {
-            "vocabulary_diversity" => sv.signals.vocabulary_diversity,
-            "question_generation" => sv.signals.question_generation,
-            "thought_lifecycle" => sv.signals.thought_lifecycle,
-            "evidence_grounding" => sv.signals.evidence_grounding,
-            _ => None,
-        })
+        .filter_map(|sv| sv.signals.get(name))
         .collect()
 }
 
@@ -34,6 +28,16 @@ pub fn std_dev(values: &[f64]) -> Option<f64> {
     Some(variance.sqrt())
 }
 
+/// Exponentially weighted mean, oldest value first.
+/// `alpha` is the weight given to each new value (0.0–1.0).
+pub fn ewma(values: &[f64], alpha: f64) -> Option<f64> {
+    let (first, rest) = values.split_first()?;
+    Some(
+        rest.iter()
+            .fold(*first, |acc, v| alpha * v + (1.0 - alpha) * acc),
+    )
+}
+
 /// Percentile rank of `value` within a set of values (0.0–100.0).
 pub fn percentile_rank(value: f64, values: &[f64]) -> f64 {
     if values.is_empty() {
@@ -47,11 +51,240 @@ pub fn percentile_rank(value: f64, values: &[f64]) -> f64 {
     ((below as f64 + equal as f64 * 0.5) / values.len() as f64) * 100.0
 }
 
+/// Value at percentile `p` (0.0–100.0), linearly interpolated between ranks.
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64))
+}
+
 /// Z-score: how many standard deviations `value` is from `mean`.
 pub fn z_score(value: f64, mean: f64, std_dev: f64) -> f64 {
     (value - mean) / std_dev
 }
 
+/// Median of a set of values.
+pub fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if mid * 2 == sorted.len() {
+        Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+    } else {
+        Some(sorted[mid])
+    }
+}
+
+/// Pearson correlation of two equal-length series.
+/// None with fewer than 3 pairs or when either series is flat.
+pub fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() != y.len() || x.len() < 3 {
+        return None;
+    }
+    let (mx, my) = (mean(x)?, mean(y)?);
+    let mut cov = 0.0;
+    let mut vx = 0.0;
+    let mut vy = 0.0;
+    for (a, b) in x.iter().zip(y) {
+        cov += (a - mx) * (b - my);
+        vx += (a - mx).powi(2);
+        vy += (b - my).powi(2);
+    }
+    if vx <= f64::EPSILON || vy <= f64::EPSILON {
+        return None;
+    }
+    Some((cov / (vx * vy).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Ranks starting at 1, with tied values sharing their average rank.
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        let rank = (i + j) as f64 / 2.0 + 1.0;
+        for &k in &order[i..=j] {
+            ranks[k] = rank;
+        }
+        i = j + 1;
+    }
+    ranks
+}
+
+/// Spearman rank correlation: Pearson over the ranks.
+pub fn spearman(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() != y.len() {
+        return None;
+    }
+    pearson(&ranks(x), &ranks(y))
+}
+
+/// Standard normal cumulative distribution function.
+/// Uses the Abramowitz & Stegun 7.1.26 approximation of erf (error < 1.5e-7).
+pub fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// Mann-Kendall trend test.
+/// Returns (S statistic, two-sided p-value). Tied values are corrected for in the variance.
+/// Needs at least 3 values; returns None otherwise.
+pub fn mann_kendall(values: &[f64]) -> Option<(f64, f64)> {
+    let n = values.len();
+    if n < 3 {
+        return None;
+    }
+    let mut s = 0.0;
+    for i in 0..n - 1 {
+        for j in i + 1..n {
+            let d = values[j] - values[i];
+            if d > f64::EPSILON {
+                s += 1.0;
+            } else if d < -f64::EPSILON {
+                s -= 1.0;
+            }
+        }
+    }
+
+    // Tie groups reduce the variance of S
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        while j < n && (sorted[j] - sorted[i]).abs() < f64::EPSILON {
+            j += 1;
+        }
+        let t = (j - i) as f64;
+        tie_term += t * (t - 1.0) * (2.0 * t + 5.0);
+        i = j;
+    }
+    let nf = n as f64;
+    let variance = (nf * (nf - 1.0) * (2.0 * nf + 5.0) - tie_term) / 18.0;
+    if variance <= 0.0 {
+        return Some((s, 1.0));
+    }
+
+    // Continuity-corrected normal approximation
+    let z = if s > 0.0 {
+        (s - 1.0) / variance.sqrt()
+    } else if s < 0.0 {
+        (s + 1.0) / variance.sqrt()
+    } else {
+        0.0
+    };
+    let p = 2.0 * (1.0 - normal_cdf(z.abs()));
+    Some((s, p.clamp(0.0, 1.0)))
+}
+
+/// Theil-Sen slope estimator: the median of all pairwise slopes.
+/// Points are (x, y) pairs so gaps in a series keep their spacing.
+pub fn theil_sen_slope(points: &[(f64, f64)]) -> Option<f64> {
+    let mut slopes = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let dx = points[j].0 - points[i].0;
+            if dx.abs() > f64::EPSILON {
+                slopes.push((points[j].1 - points[i].1) / dx);
+            }
+        }
+    }
+    median(&slopes)
+}
+
+/// Two-sided tabular CUSUM change-point detection.
+///
+/// `k` is the allowance and `h` the decision interval, both in units of the
+/// series' noise level (estimated from the mean moving range, so a step
+/// change doesn't inflate it). After each detection the reference mean is
+/// reset to the new regime. Returns (index where the shift starts, size of
+/// the shift) pairs.
+pub fn cusum_change_points(values: &[f64], k: f64, h: f64) -> Vec<(usize, f64)> {
+    const WARMUP: usize = 5;
+    let mut points = Vec::new();
+    if values.len() < WARMUP + 1 {
+        return points;
+    }
+
+    let ranges: Vec<f64> = values.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+    let sigma = match mean(&ranges) {
+        Some(mr) if mr > f64::EPSILON => mr / 1.128,
+        _ => return points,
+    };
+
+    let mut start = 0;
+    while start + WARMUP < values.len() {
+        let reference = mean(&values[start..start + WARMUP]).unwrap_or(0.0);
+        let (mut upper, mut lower) = (0.0_f64, 0.0_f64);
+        let (mut upper_zero, mut lower_zero) = (start, start);
+        let mut detected = None;
+
+        for (i, &v) in values.iter().enumerate().skip(start) {
+            let z = (v - reference) / sigma;
+            upper = (upper + z - k).max(0.0);
+            lower = (lower - z - k).max(0.0);
+            if upper == 0.0 {
+                upper_zero = i;
+            }
+            if lower == 0.0 {
+                lower_zero = i;
+            }
+            if upper > h {
+                detected = Some((upper_zero + 1, i));
+                break;
+            }
+            if lower > h {
+                detected = Some((lower_zero + 1, i));
+                break;
+            }
+        }
+
+        let Some((change, alarm)) = detected else {
+            break;
+        };
+        let change = change.max(start + 1);
+        let before = mean(&values[start..change]).unwrap_or(reference);
+        let after = mean(&values[change..=alarm.max(change)]).unwrap_or(before);
+        points.push((change, after - before));
+        start = change;
+    }
+    points
+}
+
+/// Column of a sparkline of `width` that shows series index `index`.
+/// Mirrors the subsampling done by `sparkline`.
+pub fn sparkline_column(index: usize, len: usize, width: usize) -> usize {
+    if len <= width || width < 2 {
+        return index;
+    }
+    ((index * (width - 1)) as f64 / (len - 1) as f64).round() as usize
+}
+
 /// Detect consecutive same-direction streak at the end of a series.
 /// Returns (direction, count) where direction is 1 (up), -1 (down), 0 (flat).
 pub fn streak(values: &[f64]) -> (i8, usize) {
@@ -152,6 +385,14 @@ mod tests {
         assert_eq!(std_dev(&[5.0]), None);
     }
 
+    #[test]
+    fn ewma_weights_recent_values() {
+        let e = ewma(&[0.0, 0.0, 1.0], 0.5).unwrap();
+        assert!((e - 0.5).abs() < f64::EPSILON);
+        assert_eq!(ewma(&[2.0], 0.3), Some(2.0));
+        assert_eq!(ewma(&[], 0.3), None);
+    }
+
     #[test]
     fn percentile_rank_basic() {
         let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -160,12 +401,112 @@ mod tests {
         assert!(percentile_rank(1.0, &values) < 20.0);
     }
 
+    #[test]
+    fn percentile_interpolates() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&values, 0.0), Some(1.0));
+        assert_eq!(percentile(&values, 50.0), Some(3.0));
+        assert_eq!(percentile(&values, 100.0), Some(5.0));
+        assert_eq!(percentile(&values, 10.0), Some(1.4));
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
     #[test]
     fn z_score_basic() {
         assert!((z_score(12.0, 10.0, 2.0) - 1.0).abs() < f64::EPSILON);
         assert!((z_score(8.0, 10.0, 2.0) - (-1.0)).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn median_odd_and_even() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(median(&[4.0, 1.0, 2.0, 3.0]), Some(2.5));
+        assert_eq!(median(&[]), None);
+    }
+
+    #[test]
+    fn pearson_perfect_and_flat() {
+        let x = [1.0, 2.0, 3.0, 4.0];
+        assert!((pearson(&x, &[2.0, 4.0, 6.0, 8.0]).unwrap() - 1.0).abs() < 1e-9);
+        assert!((pearson(&x, &[4.0, 3.0, 2.0, 1.0]).unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(pearson(&x, &[1.0, 1.0, 1.0, 1.0]), None);
+        assert_eq!(pearson(&x[..2], &[1.0, 2.0]), None);
+    }
+
+    #[test]
+    fn spearman_is_rank_based() {
+        assert_eq!(ranks(&[10.0, 30.0, 20.0, 20.0]), vec![1.0, 4.0, 2.5, 2.5]);
+        // Monotonic but not linear
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [1.0, 4.0, 9.0, 16.0, 100.0];
+        assert!((spearman(&x, &y).unwrap() - 1.0).abs() < 1e-9);
+        assert!(pearson(&x, &y).unwrap() < 0.99);
+    }
+
+    #[test]
+    fn normal_cdf_known_values() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-3);
+        assert!((normal_cdf(-1.96) - 0.025).abs() < 1e-3);
+    }
+
+    #[test]
+    fn mann_kendall_monotonic_is_significant() {
+        let values: Vec<f64> = (0..10).map(|i| 10.0 - i as f64).collect();
+        let (s, p) = mann_kendall(&values).unwrap();
+        assert_eq!(s, -45.0);
+        assert!(p < 0.01);
+    }
+
+    #[test]
+    fn mann_kendall_single_outlier_not_significant() {
+        let values = [0.7, 0.71, 0.69, 0.7, 0.72, 0.7, 0.69, 0.71, 0.7, 0.3];
+        let (_, p) = mann_kendall(&values).unwrap();
+        assert!(p > 0.05);
+    }
+
+    #[test]
+    fn mann_kendall_too_short() {
+        assert!(mann_kendall(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn theil_sen_ignores_outlier() {
+        let points: Vec<(f64, f64)> = (0..10)
+            .map(|i| (i as f64, if i == 9 { 100.0 } else { i as f64 * 2.0 }))
+            .collect();
+        let slope = theil_sen_slope(&points).unwrap();
+        assert!((slope - 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn cusum_finds_step_change() {
+        let mut values = vec![0.70, 0.71, 0.69, 0.70, 0.72, 0.70, 0.69, 0.71];
+        values.extend([0.40, 0.41, 0.39, 0.40, 0.42, 0.40]);
+        let points = cusum_change_points(&values, 0.5, 4.0);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].0, 8);
+        assert!((points[0].1 + 0.3).abs() < 0.05);
+    }
+
+    #[test]
+    fn cusum_quiet_on_noise() {
+        let values = [0.70, 0.71, 0.69, 0.70, 0.72, 0.70, 0.69, 0.71, 0.70, 0.72];
+        assert!(cusum_change_points(&values, 0.5, 4.0).is_empty());
+    }
+
+    #[test]
+    fn cusum_flat_series() {
+        assert!(cusum_change_points(&[1.0; 10], 0.5, 4.0).is_empty());
+    }
+
+    #[test]
+    fn sparkline_column_maps_subsampled_index() {
+        assert_eq!(sparkline_column(3, 10, 20), 3);
+        assert_eq!(sparkline_column(0, 100, 10), 0);
+        assert_eq!(sparkline_column(99, 100, 10), 9);
+    }
+
     #[test]
     fn sparkline_flat() {
         let spark = sparkline(&[5.0, 5.0, 5.0, 5.0], 4);
@@ -234,10 +575,11 @@ mod tests {
             signals: Signals {
                 vocabulary_diversity: Some(0.5),
                 question_generation: Some(3.0),
-                thought_lifecycle: None,
                 evidence_grounding: Some(0.8),
+                ..Signals::default()
             },
             document_hashes: HashMap::new(),
+            tags: Vec::new(),
         }];
         assert_eq!(signal_series(&history, "vocabulary_diversity"), vec![0.5]);
         assert!(signal_series(&history, "thought_lifecycle").is_empty());
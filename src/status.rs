@@ -1,14 +1,12 @@
 use owo_colors::OwoColorize;
 
-use crate::state::{self, AlertLevel, Analysis, Config, SignalVector, Trend};
-use crate::stats;
-
-const SIGNAL_NAMES: [&str; 4] = [
-    "vocabulary_diversity",
-    "question_generation",
-    "thought_lifecycle",
-    "evidence_grounding",
-];
+use crate::alerts::{self, AckStatus};
+use crate::analyze::{friendly_name, SIGNAL_NAMES};
+use crate::state::{
+    self, AlertLevel, AlertState, Analysis, Annotation, ChangePoint, Config, Epoch, SignalVector,
+    Trend, Zone,
+};
+use crate::{analyze, annotations, epochs, stats};
 
 const SPARKLINE_WIDTH: usize = 20;
 
@@ -16,19 +14,42 @@ pub fn run(json_output: bool) -> Result<(), String> {
     let config = state::load_config()?;
     let history = state::load_signals()?;
     let analysis = state::load_analysis()?;
+    let alert_state = state::load_alert_state()?;
+    let annotations = state::load_annotations()?;
+    let epochs = state::load_epochs()?;
 
     if json_output {
-        return print_json(&config, &history, &analysis);
+        return print_json(
+            &config,
+            &history,
+            &analysis,
+            &alert_state,
+            &annotations,
+            &epochs,
+        );
     }
 
-    print_dashboard(&config, &history, &analysis)
+    print_dashboard(
+        &config,
+        &history,
+        &analysis,
+        &alert_state,
+        &annotations,
+        &epochs,
+    )
 }
 
 fn print_dashboard(
     config: &Config,
     history: &[SignalVector],
     analysis: &Option<Analysis>,
+    alert_state: &AlertState,
+    annotations: &[Annotation],
+    epochs: &[Epoch],
 ) -> Result<(), String> {
+    // Statistics are computed within the current epoch only
+    let in_epoch = &history[epochs::current_start(history, epochs)..];
+
     // Header
     println!();
     println!("  {} — cognitive health dashboard", "vigil-echo".bold());
@@ -36,6 +57,27 @@ fn print_dashboard(
 
     // Status line
     print_status_line(analysis, history.len(), config.window_size);
+    if alert_state.level != AlertLevel::Healthy {
+        if let Some(since) = &alert_state.since {
+            let signals = if alert_state.signals.is_empty() {
+                String::new()
+            } else {
+                format!(" | {}", alert_state.signals.join(", "))
+            };
+            println!(
+                "  Since {} ({} sessions){}",
+                since, alert_state.sessions_at_level, signals
+            );
+        }
+    }
+    if let Some(epoch) = epochs::current(epochs) {
+        println!(
+            "  Epoch: {} since {} ({} collections)",
+            epoch.name,
+            epoch.started,
+            in_epoch.len()
+        );
+    }
 
     // Signals with sparklines
     println!();
@@ -43,22 +85,52 @@ fn print_dashboard(
     if history.is_empty() {
         println!("    No signals collected yet. Run `vigil-echo collect` after a session.");
     } else {
-        for &name in &SIGNAL_NAMES {
-            print_signal_row(name, history, analysis);
+        for &name in SIGNAL_NAMES {
+            print_signal_row(name, config, history, in_epoch, analysis);
+        }
+        print_composite_row(config, history);
+        let markers = annotation_markers(history, annotations);
+        if !markers.is_empty() {
+            println!("    {:<24} {:>6}  {}", "annotations", "", markers.cyan());
         }
     }
 
     // Statistics
-    if history.len() >= 3 {
+    if in_epoch.len() >= 3 {
         println!();
         println!("  {}", "Statistics".bold());
-        for &name in &SIGNAL_NAMES {
-            print_stats_row(name, history);
+        for &name in SIGNAL_NAMES {
+            print_stats_row(name, in_epoch);
+        }
+    }
+
+    // Annotations within the collected history
+    let placed: Vec<(usize, &Annotation)> = annotations
+        .iter()
+        .filter_map(|a| annotations::collection_index(history, a).map(|i| (i, a)))
+        .collect();
+    if !placed.is_empty() {
+        println!();
+        println!("  {}", "Annotations".bold());
+        for (index, a) in placed {
+            let reset = if a.reset_baseline {
+                format!("  {}", "(baseline reset)".dimmed())
+            } else {
+                String::new()
+            };
+            println!(
+                "    {} {} {} (collection {}){}",
+                "|".cyan(),
+                a.timestamp,
+                a.note,
+                index,
+                reset
+            );
         }
     }
 
     // Anomalies
-    let anomalies = detect_anomalies(history);
+    let anomalies = detect_anomalies(in_epoch);
     if !anomalies.is_empty() {
         println!();
         println!("  {}", "Anomalies".bold());
@@ -69,6 +141,20 @@ fn print_dashboard(
 
     // Alerts from analysis
     if let Some(analysis) = analysis {
+        if !analysis.change_points.is_empty() {
+            println!();
+            println!("  {}", "Change points".bold());
+            for cp in &analysis.change_points {
+                println!(
+                    "    {} {} shifted {:+.2} at collection {} ({})",
+                    "^".magenta(),
+                    friendly_name(&cp.signal),
+                    cp.shift,
+                    cp.index,
+                    cp.timestamp,
+                );
+            }
+        }
         if !analysis.watch_messages.is_empty() {
             println!();
             println!("  {}", "Alerts".bold());
@@ -76,6 +162,38 @@ fn print_dashboard(
                 println!("    {} {msg}", "!".yellow().bold());
             }
         }
+        if !alert_state.acks.is_empty() {
+            println!();
+            println!("  {}", "Acknowledged".bold());
+            let now = state::now_epoch_secs();
+            for ack in &alert_state.acks {
+                let scope = ack
+                    .signal
+                    .as_deref()
+                    .map(friendly_name)
+                    .unwrap_or("overall level");
+                let until = ack
+                    .until
+                    .as_deref()
+                    .map(|u| format!(" until {u}"))
+                    .unwrap_or_default();
+                let status = match alerts::ack_status(ack, analysis, config, now) {
+                    AckStatus::Active => String::new(),
+                    AckStatus::Reraised(reason) => {
+                        format!("  {}", format!("re-raised: {reason}").red())
+                    }
+                    AckStatus::Expired => format!("  {}", "expired".dimmed()),
+                };
+                println!(
+                    "    {} {} — {}{}{}",
+                    "✓".dimmed(),
+                    scope,
+                    ack.note,
+                    until.dimmed(),
+                    status
+                );
+            }
+        }
         if let Some(highlight) = &analysis.highlight {
             println!();
             println!("  {} {highlight}", "✦".green());
@@ -108,8 +226,8 @@ fn print_status_line(analysis: &Option<Analysis>, data_points: usize, window: us
 
     let counts = if let Some(a) = analysis {
         format!(
-            " | {} improving, {} stable, {} declining",
-            a.improving_count, a.stable_count, a.declining_count
+            " | {} improving, {} stable, {} worsening",
+            a.improving_count, a.stable_count, a.worsening_count
         )
     } else {
         String::new()
@@ -118,22 +236,30 @@ fn print_status_line(analysis: &Option<Analysis>, data_points: usize, window: us
     println!("  Status: {level}    {data_points} data points | window: {window}{counts}");
 }
 
-fn print_signal_row(name: &str, history: &[SignalVector], analysis: &Option<Analysis>) {
+fn print_signal_row(
+    name: &str,
+    config: &Config,
+    history: &[SignalVector],
+    in_epoch: &[SignalVector],
+    analysis: &Option<Analysis>,
+) {
     let series = stats::signal_series(history, name);
+    let epoch_series = stats::signal_series(in_epoch, name);
     let current = series.last().copied();
     let spark = stats::sparkline(&series, SPARKLINE_WIDTH);
 
     // Color the value based on health thresholds
     let val_str = match current {
         Some(v) => {
-            let formatted = format!("{:.2}", v);
-            match signal_zone(name, v) {
-                Zone::Healthy => format!("{}", formatted.green()),
-                Zone::Watch => format!("{}", formatted.yellow()),
-                Zone::Concern => format!("{}", formatted.red()),
+            let formatted = format!("{:>6.2}", v);
+            match config.zones.get(name).map(|b| b.zone(v)) {
+                Some(Zone::Healthy) => format!("{}", formatted.green()),
+                Some(Zone::Watch) => format!("{}", formatted.yellow()),
+                Some(Zone::Concern) => format!("{}", formatted.red()),
+                None => formatted,
             }
         }
-        None => format!("{}", "--".dimmed()),
+        None => format!("{}", format!("{:>6}", "--").dimmed()),
     };
 
     // Trend arrow and delta from analysis
@@ -142,7 +268,7 @@ fn print_signal_row(name: &str, history: &[SignalVector], analysis: &Option<Anal
             let arrow = match trend.trend {
                 Trend::Improving => format!("{}", "↑".green()),
                 Trend::Stable => format!("{}", "→".dimmed()),
-                Trend::Declining => format!("{}", "↓".red()),
+                Trend::Worsening => format!("{}", "↓".red()),
             };
             (arrow, format!("{:+.2}", trend.delta))
         } else {
@@ -154,7 +280,7 @@ fn print_signal_row(name: &str, history: &[SignalVector], analysis: &Option<Anal
 
     // Rarity indicator
     let rarity = if let Some(v) = current {
-        if let (Some(m), Some(sd)) = (stats::mean(&series), stats::std_dev(&series)) {
+        if let (Some(m), Some(sd)) = (stats::mean(&epoch_series), stats::std_dev(&epoch_series)) {
             if sd > f64::EPSILON {
                 let z = stats::z_score(v, m, sd);
                 if z.abs() >= 2.0 {
@@ -175,7 +301,7 @@ fn print_signal_row(name: &str, history: &[SignalVector], analysis: &Option<Anal
     };
 
     println!(
-        "    {:<24} {:>6}  {}  {} {:>6} {}",
+        "    {:<24} {}  {}  {} {:>6} {}",
         friendly_name(name),
         val_str,
         spark,
@@ -183,6 +309,77 @@ fn print_signal_row(name: &str, history: &[SignalVector], analysis: &Option<Anal
         delta_str,
         rarity,
     );
+
+    // Change-point markers under the sparkline columns
+    if let Some(analysis) = analysis {
+        let markers = change_point_markers(name, history, &analysis.change_points);
+        if !markers.trim().is_empty() {
+            println!("    {:<24} {:>6}  {}", "", "", markers.magenta());
+        }
+    }
+}
+
+/// Composite health score per collection, with its own sparkline.
+fn print_composite_row(config: &Config, history: &[SignalVector]) {
+    let series = composite_series(config, history);
+    let Some(current) = series.last().copied() else {
+        return;
+    };
+    let formatted = format!("{:>6.0}", current);
+    let val_str = if current >= 75.0 {
+        format!("{}", formatted.green())
+    } else if current >= 50.0 {
+        format!("{}", formatted.yellow())
+    } else {
+        format!("{}", formatted.red())
+    };
+    println!(
+        "    {:<24} {}  {}",
+        "composite health".bold(),
+        val_str,
+        stats::sparkline(&series, SPARKLINE_WIDTH),
+    );
+}
+
+fn composite_series(config: &Config, history: &[SignalVector]) -> Vec<f64> {
+    history
+        .iter()
+        .filter_map(|sv| analyze::composite_score(sv, config))
+        .collect()
+}
+
+/// A row of `|` (or `‖` for baseline resets) aligned with the sparkline
+/// columns where annotations fall.
+fn annotation_markers(history: &[SignalVector], annotations: &[Annotation]) -> String {
+    let width = history.len().min(SPARKLINE_WIDTH);
+    let mut row = vec![' '; width];
+    for a in annotations {
+        if let Some(index) = annotations::collection_index(history, a) {
+            let col = stats::sparkline_column(index, history.len(), SPARKLINE_WIDTH);
+            if col < width && row[col] != '‖' {
+                row[col] = if a.reset_baseline { '‖' } else { '|' };
+            }
+        }
+    }
+    row.into_iter().collect::<String>().trim_end().to_string()
+}
+
+/// A row of `^` aligned with the sparkline columns where change points start.
+fn change_point_markers(name: &str, history: &[SignalVector], points: &[ChangePoint]) -> String {
+    let series_len = stats::signal_series(history, name).len();
+    let width = series_len.min(SPARKLINE_WIDTH);
+    let mut row = vec![' '; width];
+    for cp in points.iter().filter(|cp| cp.signal == name) {
+        // Position within the series, which skips collections where the signal was missing
+        let pos = stats::signal_series(&history[..cp.index.min(history.len())], name).len();
+        if pos < series_len {
+            let col = stats::sparkline_column(pos, series_len, SPARKLINE_WIDTH);
+            if col < width {
+                row[col] = '^';
+            }
+        }
+    }
+    row.into_iter().collect::<String>().trim_end().to_string()
 }
 
 fn print_stats_row(name: &str, history: &[SignalVector]) {
@@ -214,9 +411,9 @@ fn print_stats_row(name: &str, history: &[SignalVector]) {
     );
 }
 
-fn detect_anomalies(history: &[SignalVector]) -> Vec<String> {
+pub fn detect_anomalies(history: &[SignalVector]) -> Vec<String> {
     let mut anomalies = Vec::new();
-    for &name in &SIGNAL_NAMES {
+    for &name in SIGNAL_NAMES {
         let series = stats::signal_series(history, name);
         if series.len() < 5 {
             continue;
@@ -257,41 +454,50 @@ fn print_json(
     config: &Config,
     history: &[SignalVector],
     analysis: &Option<Analysis>,
+    alert_state: &AlertState,
+    annotations: &[Annotation],
+    epochs: &[Epoch],
 ) -> Result<(), String> {
     let mut output = serde_json::Map::new();
+    let in_epoch = &history[epochs::current_start(history, epochs)..];
 
-    // Per-signal stats
+    // Per-signal stats (mean, sd, percentile within the current epoch)
     let mut signals_json = serde_json::Map::new();
-    for &name in &SIGNAL_NAMES {
+    for &name in SIGNAL_NAMES {
         let series = stats::signal_series(history, name);
+        let epoch_series = stats::signal_series(in_epoch, name);
         let mut sig = serde_json::Map::new();
 
         let current = series.last().copied();
         sig.insert("current".into(), json_opt(current));
-        sig.insert("mean".into(), json_opt(stats::mean(&series)));
-        sig.insert("std_dev".into(), json_opt(stats::std_dev(&series)));
+        sig.insert("mean".into(), json_opt(stats::mean(&epoch_series)));
+        sig.insert("std_dev".into(), json_opt(stats::std_dev(&epoch_series)));
         sig.insert(
             "sparkline".into(),
             serde_json::Value::String(stats::sparkline(&series, SPARKLINE_WIDTH)),
         );
 
         if let Some(v) = current {
-            if let Some(n) = serde_json::Number::from_f64(stats::percentile_rank(v, &series)) {
+            if let Some(n) = serde_json::Number::from_f64(stats::percentile_rank(v, &epoch_series))
+            {
                 sig.insert("percentile".into(), serde_json::Value::Number(n));
             }
-            if let (Some(m), Some(sd)) = (stats::mean(&series), stats::std_dev(&series)) {
+            if let (Some(m), Some(sd)) = (stats::mean(&epoch_series), stats::std_dev(&epoch_series))
+            {
                 if sd > f64::EPSILON {
                     if let Some(n) = serde_json::Number::from_f64(stats::z_score(v, m, sd)) {
                         sig.insert("z_score".into(), serde_json::Value::Number(n));
                     }
                 }
             }
-            let zone = match signal_zone(name, v) {
-                Zone::Healthy => "healthy",
-                Zone::Watch => "watch",
-                Zone::Concern => "concern",
-            };
-            sig.insert("health_zone".into(), serde_json::Value::String(zone.into()));
+            if let Some(zone) = config.zones.get(name).map(|b| b.zone(v)) {
+                let zone = match zone {
+                    Zone::Healthy => "healthy",
+                    Zone::Watch => "watch",
+                    Zone::Concern => "concern",
+                };
+                sig.insert("health_zone".into(), serde_json::Value::String(zone.into()));
+            }
         }
 
         let (streak_dir, streak_count) = stats::streak(&series);
@@ -313,6 +519,11 @@ fn print_json(
                 if let Some(n) = serde_json::Number::from_f64(trend.delta) {
                     sig.insert("delta".into(), serde_json::Value::Number(n));
                 }
+                if let Some(n) = serde_json::Number::from_f64(trend.health_delta) {
+                    sig.insert("health_delta".into(), serde_json::Value::Number(n));
+                }
+                sig.insert("p_value".into(), json_opt(trend.p_value));
+                sig.insert("slope".into(), json_opt(trend.slope));
             }
         }
 
@@ -320,6 +531,17 @@ fn print_json(
     }
     output.insert("signals".into(), serde_json::Value::Object(signals_json));
 
+    // Composite health score
+    let composite = composite_series(config, history);
+    let mut comp = serde_json::Map::new();
+    comp.insert("current".into(), json_opt(composite.last().copied()));
+    comp.insert("mean".into(), json_opt(stats::mean(&composite)));
+    comp.insert(
+        "sparkline".into(),
+        serde_json::Value::String(stats::sparkline(&composite, SPARKLINE_WIDTH)),
+    );
+    output.insert("composite".into(), serde_json::Value::Object(comp));
+
     // Alert level
     if let Some(analysis) = analysis {
         output.insert(
@@ -330,6 +552,26 @@ fn print_json(
             "data_points".into(),
             serde_json::Value::Number(serde_json::Number::from(analysis.data_points as u64)),
         );
+        output.insert(
+            "alert_since".into(),
+            serde_json::to_value(&alert_state.since)
+                .map_err(|e| format!("JSON serialization failed: {e}"))?,
+        );
+        output.insert(
+            "alert_signals".into(),
+            serde_json::to_value(&alert_state.signals)
+                .map_err(|e| format!("JSON serialization failed: {e}"))?,
+        );
+        output.insert(
+            "acks".into(),
+            serde_json::to_value(&alert_state.acks)
+                .map_err(|e| format!("JSON serialization failed: {e}"))?,
+        );
+        output.insert(
+            "change_points".into(),
+            serde_json::to_value(&analysis.change_points)
+                .map_err(|e| format!("JSON serialization failed: {e}"))?,
+        );
         output.insert(
             "watch_messages".into(),
             serde_json::Value::Array(
@@ -343,7 +585,7 @@ fn print_json(
     }
 
     // Anomalies
-    let anomalies = detect_anomalies(history);
+    let anomalies = detect_anomalies(in_epoch);
     output.insert(
         "anomalies".into(),
         serde_json::Value::Array(
@@ -354,6 +596,22 @@ fn print_json(
         ),
     );
 
+    output.insert(
+        "epoch".into(),
+        match epochs::current(epochs) {
+            Some(epoch) => serde_json::json!({
+                "name": epoch.name,
+                "started": epoch.started,
+                "collections": in_epoch.len(),
+            }),
+            None => serde_json::Value::Null,
+        },
+    );
+    output.insert(
+        "annotations".into(),
+        serde_json::to_value(annotations).map_err(|e| format!("JSON serialization failed: {e}"))?,
+    );
+
     // Config
     let mut cfg = serde_json::Map::new();
     cfg.insert(
@@ -378,38 +636,3 @@ fn json_opt(opt: Option<f64>) -> serde_json::Value {
         .map(serde_json::Value::Number)
         .unwrap_or(serde_json::Value::Null)
 }
-
-// --- Helpers ---
-
-enum Zone {
-    Healthy,
-    Watch,
-    Concern,
-}
-
-fn signal_zone(name: &str, value: f64) -> Zone {
-    let (red_below, yellow_below) = match name {
-        "vocabulary_diversity" => (0.25, 0.40),
-        "question_generation" => (2.0, 4.0),
-        "thought_lifecycle" => (0.15, 0.30),
-        "evidence_grounding" => (0.40, 0.60),
-        _ => (0.25, 0.50),
-    };
-    if value < red_below {
-        Zone::Concern
-    } else if value < yellow_below {
-        Zone::Watch
-    } else {
-        Zone::Healthy
-    }
-}
-
-fn friendly_name(name: &str) -> &str {
-    match name {
-        "vocabulary_diversity" => "vocabulary diversity",
-        "question_generation" => "question generation",
-        "thought_lifecycle" => "thought lifecycle",
-        "evidence_grounding" => "evidence grounding",
-        _ => name,
-    }
-}